@@ -0,0 +1,203 @@
+use crate::bindings::require_js;
+use crate::state::StateCell;
+use crate::{State, Ticker, TickerError};
+use std::rc::Rc;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+use web_sys::{AbortController, AbortSignal};
+
+const ABORT: &str = "abort";
+
+/// Listener of `abort` event of an [AbortSignal], removed on drop.
+struct AbortListener {
+    signal: AbortSignal,
+    cb: Closure<dyn FnMut()>,
+}
+
+impl AbortListener {
+    fn new(
+        signal: &AbortSignal,
+        listener: impl FnOnce(JsValue) + 'static,
+    ) -> Result<Self, TickerError> {
+        let signal_ = signal.clone();
+        let mut listener = Some(listener);
+        let cb = Closure::new(move || {
+            if let Some(listener) = listener.take() {
+                listener(signal_.reason());
+            }
+        });
+        signal.add_event_listener_with_callback(ABORT, cb.as_ref().unchecked_ref())?;
+        Ok(Self {
+            signal: signal.clone(),
+            cb,
+        })
+    }
+}
+
+impl Drop for AbortListener {
+    fn drop(&mut self) {
+        let _ = self
+            .signal
+            .remove_event_listener_with_callback(ABORT, self.cb.as_ref().unchecked_ref());
+    }
+}
+
+/// [AbortListener]s of [Ticker::stop_on_abort] owned by a ticker, removed once it drops.
+pub(crate) struct AbortListeners(WasmRefCell<Vec<AbortListener>>);
+
+impl Default for AbortListeners {
+    fn default() -> Self {
+        Self(WasmRefCell::new(Vec::new()))
+    }
+}
+
+/// Call `f` once `signal` aborts, at once if already aborted.
+///
+/// The listener is kept by `listeners` of the ticker it stops,
+/// so `f` should only weakly reference that ticker.
+pub(crate) fn on_abort(
+    listeners: &AbortListeners,
+    signal: &AbortSignal,
+    f: impl FnOnce() + 'static,
+) -> Result<(), TickerError> {
+    if signal.aborted() {
+        f();
+        return Ok(());
+    }
+    let listener = AbortListener::new(signal, move |_| f())?;
+    let mut listeners = listeners.0.borrow_mut();
+    // Listeners of aborted signals were called already
+    listeners.retain(|listener| !listener.signal.aborted());
+    listeners.push(listener);
+    Ok(())
+}
+
+/// [Ticker::stop_on_abort] of any ticker, keeping a clone of `ticker` and the listener
+/// until the signal aborts or `ticker` stops as reported by [Ticker::on_state_change].
+pub(crate) fn stop_on_abort_once<T: Ticker + Clone + 'static>(
+    ticker: &T,
+    signal: &AbortSignal,
+) -> Result<(), TickerError> {
+    if signal.aborted() {
+        ticker.stop();
+        return Ok(());
+    }
+    let slot = Rc::new(WasmRefCell::new(None::<AbortListener>));
+    let slot_ = slot.clone();
+    let ticker_ = ticker.clone();
+    let listener = AbortListener::new(signal, move |_| {
+        // Break reference cycle of the listener owning itself
+        let listener = slot_.borrow_mut().take();
+        ticker_.stop();
+        drop(listener);
+    })?;
+    slot.borrow_mut().replace(listener);
+    ticker.on_state_change(Box::new(move |state| {
+        if state.ne(&State::Started) {
+            slot.borrow_mut().take();
+        }
+    }));
+    Ok(())
+}
+
+/// Abort `controller` on [State::Stopped], or with the error on [State::Error],
+/// returning whether aborted.
+fn abort_on_stop(controller: &AbortController, state: &State) -> bool {
+    match state {
+        State::Stopped => controller.abort(),
+        State::Error(e) => controller.abort_with_reason(&e.clone().into()),
+        State::Started => return false,
+    }
+    true
+}
+
+/// [AbortSignal] aborted on next stop of `state`, the observer is removed once aborted.
+pub(crate) fn abort_signal(state: &StateCell) -> Result<AbortSignal, TickerError> {
+    require_js("AbortController")?;
+    let controller = AbortController::new()?;
+    let signal = controller.signal();
+    state.observe_until(Box::new(move |state| abort_on_stop(&controller, state)));
+    Ok(signal)
+}
+
+/// Like [abort_signal] by [Ticker::on_state_change] of any ticker,
+/// the observer can't be removed but does nothing once aborted.
+pub(crate) fn observe_abort_signal(
+    ticker: &(impl Ticker + ?Sized),
+) -> Result<AbortSignal, TickerError> {
+    require_js("AbortController")?;
+    let controller = AbortController::new()?;
+    let signal = controller.signal();
+    let mut controller = Some(controller);
+    ticker.on_state_change(Box::new(move |state| {
+        if let Some(c) = &controller {
+            if abort_on_stop(c, state) {
+                controller = None;
+            }
+        }
+    }));
+    Ok(signal)
+}
+
+struct Pending {
+    task: Option<Box<dyn FnOnce()>>,
+    on_abort: Option<Box<dyn FnOnce(JsValue)>>,
+    listener: Option<AbortListener>,
+}
+
+/// Queue `task` once by `spawn`, dropping it and calling `on_abort` with the reason
+/// if `signal` aborts before it runs.
+///
+/// Fails with [TickerError::Cancelled] without queuing if already aborted,
+/// callers settling a promise check [AbortSignal::aborted] beforehand.
+pub(crate) fn spawn_with_signal(
+    spawn: impl FnOnce(Box<dyn FnOnce()>) -> Result<(), TickerError>,
+    task: impl FnOnce() + 'static,
+    signal: &AbortSignal,
+    on_abort: impl FnOnce(JsValue) + 'static,
+) -> Result<(), TickerError> {
+    if signal.aborted() {
+        return Err(TickerError::Cancelled);
+    }
+    let pending = Rc::new(WasmRefCell::new(Pending {
+        task: Some(Box::new(task)),
+        on_abort: Some(Box::new(on_abort)),
+        listener: None,
+    }));
+
+    let pending_ = pending.clone();
+    let listener = AbortListener::new(signal, move |reason| {
+        let (task, on_abort, listener) = {
+            let mut pending = pending_.borrow_mut();
+            (
+                pending.task.take(),
+                pending.on_abort.take(),
+                pending.listener.take(),
+            )
+        };
+        // Break reference cycle of the listener owning `pending`
+        drop(listener);
+        drop(task);
+        if let Some(on_abort) = on_abort {
+            on_abort(reason);
+        }
+    })?;
+    pending.borrow_mut().listener.replace(listener);
+
+    let pending_ = pending.clone();
+    spawn(Box::new(move || {
+        let (task, listener) = {
+            let mut pending = pending_.borrow_mut();
+            pending.on_abort.take();
+            (pending.task.take(), pending.listener.take())
+        };
+        drop(listener);
+        if let Some(task) = task {
+            task();
+        }
+    }))
+    .map_err(|e| {
+        pending.borrow_mut().listener.take();
+        e
+    })
+}
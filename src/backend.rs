@@ -0,0 +1,114 @@
+use crate::bindings::*;
+use crate::ScheduleBackend;
+use std::marker::PhantomData;
+use wasm_bindgen::JsValue;
+
+pub use crate::bindings::{AnimationFrameToken, ImmediateToken, TimeoutToken};
+
+/// [`setTimeout`](https://developer.mozilla.org/docs/Web/API/setTimeout) without delay.
+pub struct SetTimeout;
+
+impl ScheduleBackend for SetTimeout {
+    type Token = TimeoutToken;
+
+    fn available() -> bool {
+        has_set_timeout()
+    }
+
+    fn schedule(cb: &js_sys::Function) -> Result<Self::Token, JsValue> {
+        __wasm_ticker_binding_set_timeout(cb)
+    }
+
+    fn cancel(token: Self::Token) {
+        __wasm_ticker_binding_clear_timeout(token)
+    }
+
+    fn js_functions() -> Option<(js_sys::Function, js_sys::Function)> {
+        global_functions("setTimeout", "clearTimeout")
+    }
+}
+
+/// [`setImmediate`](https://nodejs.org/en-us/learn/asynchronous-work/understanding-setimmediate),
+/// available in Node and Bun.
+pub struct SetImmediate;
+
+impl ScheduleBackend for SetImmediate {
+    type Token = ImmediateToken;
+
+    fn available() -> bool {
+        has_set_immediate()
+    }
+
+    fn schedule(cb: &js_sys::Function) -> Result<Self::Token, JsValue> {
+        __wasm_ticker_binding_set_immediate(cb)
+    }
+
+    fn cancel(token: Self::Token) {
+        __wasm_ticker_binding_clear_immediate(token)
+    }
+
+    fn js_functions() -> Option<(js_sys::Function, js_sys::Function)> {
+        global_functions("setImmediate", "clearImmediate")
+    }
+}
+
+/// [`requestAnimationFrame`](https://developer.mozilla.org/docs/Web/API/Window/requestAnimationFrame),
+/// available in browser Window context.
+///
+/// Callback is called with frame timestamp.
+pub struct RequestAnimationFrame;
+
+impl ScheduleBackend for RequestAnimationFrame {
+    type Token = AnimationFrameToken;
+
+    fn available() -> bool {
+        has_request_animation_frame()
+    }
+
+    fn schedule(cb: &js_sys::Function) -> Result<Self::Token, JsValue> {
+        __wasm_ticker_binding_request_animation_frame(cb)
+    }
+
+    fn cancel(token: Self::Token) {
+        __wasm_ticker_binding_cancel_animation_frame(token)
+    }
+
+    fn js_functions() -> Option<(js_sys::Function, js_sys::Function)> {
+        global_functions("requestAnimationFrame", "cancelAnimationFrame")
+    }
+}
+
+/// Wraps backend `B` to `unref()` each token once scheduled,
+/// so pending ticks don't keep Node or Bun process alive, e.g. polling in a CLI.
+///
+/// Tokens without `unref`, like numbers in browsers and Deno, are kept as is.
+///
+/// ```ignore
+/// let ticker = BackendTickerFactory::<Unref<SetTimeout>>::new(poll)?;
+/// ticker.start()?;
+/// assert_eq!(ticker.token().unwrap().has_ref(), Some(false));
+/// ```
+pub struct Unref<B: ScheduleBackend>(PhantomData<B>);
+
+impl<B: ScheduleBackend> ScheduleBackend for Unref<B> {
+    type Token = B::Token;
+
+    fn available() -> bool {
+        B::available()
+    }
+
+    fn schedule(cb: &js_sys::Function) -> Result<Self::Token, JsValue> {
+        let token = B::schedule(cb)?;
+        unref(token.as_ref());
+        Ok(token)
+    }
+
+    fn cancel(token: Self::Token) {
+        B::cancel(token)
+    }
+
+    fn js_functions() -> Option<(js_sys::Function, js_sys::Function)> {
+        let (schedule, cancel) = B::js_functions()?;
+        Some((__wasm_ticker_unref_schedule(&schedule), cancel))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::TickerError;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -19,6 +20,11 @@ extern "C" {
     pub fn __wasm_ticker_binding_set_timeout(
         cb: &js_sys::Function,
     ) -> Result<TimeoutToken, JsValue>;
+    #[wasm_bindgen(catch, js_name = setTimeout)]
+    pub fn __wasm_ticker_binding_set_timeout_with_delay(
+        cb: &js_sys::Function,
+        delay: f64,
+    ) -> Result<TimeoutToken, JsValue>;
     #[wasm_bindgen(js_name = clearTimeout)]
     pub fn __wasm_ticker_binding_clear_timeout(token: TimeoutToken);
 
@@ -32,6 +38,9 @@ extern "C" {
     #[wasm_bindgen(js_name = cancelAnimationFrame)]
     pub fn __wasm_ticker_binding_cancel_animation_frame(token: AnimationFrameToken);
 
+    #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    pub fn __wasm_ticker_binding_performance_now() -> f64;
+
     pub type Resolvers;
 
     #[wasm_bindgen(catch, js_namespace = Promise, js_name = withResolvers)]
@@ -45,18 +54,306 @@ extern "C" {
     pub fn __wasm_ticker_binding_reject(this: &Resolvers) -> js_sys::Function;
 }
 
+/// Whether `name` is a property of global object, always `false` on native targets.
+pub fn has_global(name: &str) -> bool {
+    cfg!(target_family = "wasm")
+        && js_sys::Reflect::has(&js_sys::global(), &JsValue::from_str(name)).unwrap_or(false)
+}
+
+/// Property at `path` of global object if defined, always [None] on native targets.
+pub fn get_global(path: &[&str]) -> Option<JsValue> {
+    if !cfg!(target_family = "wasm") {
+        return None;
+    }
+    let mut value: JsValue = js_sys::global().into();
+    for name in path {
+        if !value.is_object() {
+            return None;
+        }
+        value = js_sys::Reflect::get(&value, &JsValue::from_str(name)).ok()?;
+    }
+    Some(value).filter(|value| !value.is_undefined())
+}
+
 pub fn has_set_immediate() -> bool {
-    js_sys::Reflect::has(&js_sys::global(), &JsValue::from_str("setImmediate")).unwrap_or(false)
+    has_global("setImmediate")
 }
 
 pub fn has_set_timeout() -> bool {
-    js_sys::Reflect::has(&js_sys::global(), &JsValue::from_str("setTimeout")).unwrap_or(false)
+    has_global("setTimeout")
 }
 
 pub fn has_request_animation_frame() -> bool {
-    js_sys::Reflect::has(
-        &js_sys::global(),
-        &JsValue::from_str("requestAnimationFrame"),
-    )
-    .unwrap_or(false)
+    has_global("requestAnimationFrame")
+}
+
+pub fn has_wait_async() -> bool {
+    has_global("SharedArrayBuffer")
+        && js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("Atomics"))
+            .and_then(|atomics| js_sys::Reflect::has(&atomics, &JsValue::from_str("waitAsync")))
+            .unwrap_or(false)
+}
+
+/// [TickerError::Unavailable] of `api` on native targets, where JavaScript imports panic.
+pub fn require_js(api: &'static str) -> Result<(), TickerError> {
+    match cfg!(target_family = "wasm") {
+        true => Ok(()),
+        false => Err(TickerError::Unavailable(api)),
+    }
+}
+
+/// `Promise.withResolvers()`, [TickerError::Unavailable] if not supported.
+pub fn promise_resolvers() -> Result<Resolvers, TickerError> {
+    const API: &str = "Promise.withResolvers";
+    require_js(API)?;
+    __wasm_ticker_binding_promise_resolvers().map_err(|_| TickerError::Unavailable(API))
+}
+
+/// Call method `name` of `target` if it is an object with such method.
+fn call_method(target: &JsValue, name: &str) -> Option<JsValue> {
+    if !target.is_object() {
+        return None;
+    }
+    let method = js_sys::Reflect::get(target, &JsValue::from_str(name)).ok()?;
+    method.dyn_ref::<js_sys::Function>()?.call0(target).ok()
+}
+
+/// `unref()` of Node and Bun timer objects and message ports, does nothing on other values.
+pub fn unref(target: &JsValue) {
+    call_method(target, "unref");
+}
+
+/// `ref()` of Node and Bun timer objects and message ports, does nothing on other values.
+pub fn ref_(target: &JsValue) {
+    call_method(target, "ref");
+}
+
+/// Node and Bun return `Timeout` and `Immediate` objects keeping the process alive while pending,
+/// browsers and Deno return numbers on which these methods do nothing.
+macro_rules! impl_ref_token {
+    ($token:ty) => {
+        impl $token {
+            /// Keep the process alive while pending, the default.
+            pub fn ref_(&self) {
+                ref_(self)
+            }
+
+            /// Let the process exit while pending.
+            pub fn unref(&self) {
+                unref(self)
+            }
+
+            /// Whether the process is kept alive, [None] if not supported.
+            pub fn has_ref(&self) -> Option<bool> {
+                call_method(self, "hasRef")?.as_bool()
+            }
+        }
+    };
+}
+
+impl_ref_token!(TimeoutToken);
+impl_ref_token!(ImmediateToken);
+
+impl TimeoutToken {
+    /// Restart the timer with its original delay, keeping the callback.
+    /// Does nothing if not supported.
+    ///
+    /// Not available on [ImmediateToken], as `Immediate` of Node has no delay to restart.
+    pub fn refresh(&self) {
+        call_method(self, "refresh");
+    }
+}
+
+/// `performance.now()`.
+#[cfg(target_family = "wasm")]
+pub fn now() -> f64 {
+    __wasm_ticker_binding_performance_now()
+}
+
+/// Milliseconds since first call, `performance.now()` is not available on native targets.
+#[cfg(not(target_family = "wasm"))]
+pub fn now() -> f64 {
+    static ORIGIN: wasm_bindgen::__rt::Lazy<std::time::Instant> =
+        wasm_bindgen::__rt::Lazy::new(std::time::Instant::now);
+    ORIGIN.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Promise of `Atomics.waitAsync` result,
+/// [None] if resolved synchronously as "not-equal" or "timed-out".
+pub fn wait_async_promise(result: &js_sys::Object) -> Result<Option<js_sys::Promise>, JsValue> {
+    let is_async = js_sys::Reflect::get(result, &JsValue::from_str("async"))?.is_truthy();
+    if !is_async {
+        return Ok(None);
+    }
+    let value = js_sys::Reflect::get(result, &JsValue::from_str("value"))?;
+    Ok(Some(value.unchecked_into()))
+}
+
+/// Call `then` of `promise`, resolved value is passed to `cb`.
+///
+/// `cb` is usually created by [Closure::once_into_js](wasm_bindgen::closure::Closure::once_into_js),
+/// so it won't leak once called.
+pub fn promise_then(promise: &js_sys::Promise, cb: &JsValue) -> Result<(), JsValue> {
+    let then: js_sys::Function =
+        js_sys::Reflect::get(promise, &JsValue::from_str("then"))?.unchecked_into();
+    then.call1(promise, cb)?;
+    Ok(())
+}
+
+/// View of current WebAssembly memory, used by `Atomics` APIs.
+pub fn memory_i32_view() -> js_sys::Int32Array {
+    let memory = wasm_bindgen::memory().unchecked_into::<js_sys::WebAssembly::Memory>();
+    js_sys::Int32Array::new(&memory.buffer())
+}
+
+/// Index of `value` in [memory_i32_view].
+pub fn memory_i32_index(value: &std::sync::atomic::AtomicI32) -> u32 {
+    (value as *const _ as usize / 4) as u32
+}
+
+#[wasm_bindgen(inline_js = r#"
+export function __wasm_ticker_scheduler(schedule, cancel) {
+    return { schedule, cancel };
+}
+
+export function __wasm_ticker_unref_schedule(schedule) {
+    return (cb) => {
+        const token = schedule(cb);
+        if (token && typeof token.unref === "function") token.unref();
+        return token;
+    };
+}
+
+export function __wasm_ticker_message_channel_scheduler() {
+    const { port1, port2 } = new MessageChannel();
+    let seq = 0;
+    let pending = 0;
+    let cb;
+    port1.onmessage = (event) => {
+        // Cancelled messages are still delivered, ignore them
+        if (event.data === pending) {
+            pending = 0;
+            cb();
+        }
+    };
+    return {
+        schedule(f) {
+            cb = f;
+            pending = ++seq;
+            port2.postMessage(pending);
+            return pending;
+        },
+        cancel(token) {
+            if (pending === token) pending = 0;
+        },
+        close() {
+            port1.close();
+            port2.close();
+        },
+    };
+}
+
+export function __wasm_ticker_function_loop(scheduler, f, thisArg, args, onError) {
+    const { schedule, cancel, close } = scheduler;
+    let started = false;
+    let token;
+    const tick = () => {
+        token = undefined;
+        if (!started) return;
+        try {
+            f.apply(thisArg, args);
+        } catch (e) {
+            started = false;
+            onError(e, false);
+            return;
+        }
+        // The function may have stopped the loop
+        if (!started) return;
+        try {
+            token = schedule(tick);
+        } catch (e) {
+            started = false;
+            onError(e, true);
+        }
+    };
+    const stop = () => {
+        started = false;
+        if (token !== undefined) {
+            cancel(token);
+            token = undefined;
+        }
+    };
+    return {
+        start() {
+            started = true;
+            try {
+                token = schedule(tick);
+            } catch (e) {
+                started = false;
+                throw e;
+            }
+        },
+        startImmediate() {
+            started = true;
+            tick();
+        },
+        stop,
+        close() {
+            stop();
+            if (close) close();
+        },
+    };
+}
+
+export function __wasm_ticker_rethrow(e) {
+    queueMicrotask(() => {
+        throw e;
+    });
+}
+"#)]
+extern "C" {
+    /// `schedule` and `cancel` functions of a backend, see [FunctionLoop].
+    pub type Scheduler;
+
+    pub fn __wasm_ticker_scheduler(
+        schedule: &js_sys::Function,
+        cancel: &js_sys::Function,
+    ) -> Scheduler;
+    pub fn __wasm_ticker_unref_schedule(schedule: &js_sys::Function) -> js_sys::Function;
+    #[wasm_bindgen(catch)]
+    pub fn __wasm_ticker_message_channel_scheduler() -> Result<Scheduler, JsValue>;
+
+    /// Applies a function on each tick and re-arms by a [Scheduler], all in JavaScript.
+    ///
+    /// `onError` is called with the exception, and whether thrown by scheduling.
+    pub type FunctionLoop;
+
+    pub fn __wasm_ticker_function_loop(
+        scheduler: &Scheduler,
+        f: &js_sys::Function,
+        this_arg: &JsValue,
+        args: &js_sys::Array,
+        on_error: &js_sys::Function,
+    ) -> FunctionLoop;
+    #[wasm_bindgen(method, catch)]
+    pub fn start(this: &FunctionLoop) -> Result<(), JsValue>;
+    #[wasm_bindgen(method, js_name = startImmediate)]
+    pub fn start_immediate(this: &FunctionLoop);
+    #[wasm_bindgen(method)]
+    pub fn stop(this: &FunctionLoop);
+    #[wasm_bindgen(method)]
+    pub fn close(this: &FunctionLoop);
+
+    /// Throw `e` from a microtask, reported as uncaught without unwinding WebAssembly.
+    pub fn __wasm_ticker_rethrow(e: &JsValue);
+}
+
+/// Global functions named `schedule` and `cancel`, [None] if either is not defined.
+pub fn global_functions(
+    schedule: &str,
+    cancel: &str,
+) -> Option<(js_sys::Function, js_sys::Function)> {
+    let schedule = get_global(&[schedule])?.dyn_into().ok()?;
+    let cancel = get_global(&[cancel])?.dyn_into().ok()?;
+    Some((schedule, cancel))
 }
@@ -0,0 +1,256 @@
+use crate::completion::CompletionCell;
+use crate::time::Instant;
+use crate::{State, Ticker, TickerError, TickerFactory};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+use web_sys::AbortSignal;
+
+/// Limits of a run of [Bounded], any reached one stops the ticker.
+#[derive(Default)]
+pub struct Bounds {
+    max_ticks: Option<u64>,
+    deadline: Option<Duration>,
+    until: Option<Box<dyn FnMut() -> bool>>,
+}
+
+impl Bounds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop after `max_ticks` ticks.
+    pub fn max_ticks(mut self, max_ticks: u64) -> Self {
+        self.max_ticks = Some(max_ticks);
+        self
+    }
+
+    /// Stop once `deadline` elapsed since start, checked on each tick.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stop once `predicate` returns `true`, checked after each tick.
+    pub fn until(mut self, predicate: impl FnMut() -> bool + 'static) -> Self {
+        self.until = Some(Box::new(predicate));
+        self
+    }
+}
+
+/// Why a run of [Bounded] ended.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Finish {
+    MaxTicks,
+    Deadline,
+    Predicate,
+    /// Stopped by [Ticker::stop], or by the task itself.
+    Stopped,
+}
+
+type Run = CompletionCell<Result<Finish, TickerError>>;
+
+struct Shared<T> {
+    bounds: WasmRefCell<Bounds>,
+    ticks: Cell<u64>,
+    started: Cell<Option<Instant>>,
+    /// Bound reached by the task, reported when the ticker stops.
+    reason: Cell<Option<Finish>>,
+    /// Completion of current run, or last one if stopped.
+    run: WasmRefCell<Rc<Run>>,
+    /// Clone of ticker to stop itself.
+    ticker: WasmRefCell<Option<T>>,
+}
+
+impl<T: Ticker> Shared<T> {
+    fn expired(&self) -> bool {
+        match (self.bounds.borrow().deadline, self.started.get()) {
+            (Some(deadline), Some(started)) => started.elapsed() >= deadline,
+            _ => false,
+        }
+    }
+
+    fn predicate(&self) -> bool {
+        // Not borrowed while calling, the predicate may access the ticker
+        let until = self.bounds.borrow_mut().until.take();
+        match until {
+            Some(mut until) => {
+                let ret = until();
+                let mut bounds = self.bounds.borrow_mut();
+                if bounds.until.is_none() {
+                    bounds.until = Some(until);
+                }
+                ret
+            }
+            None => false,
+        }
+    }
+
+    fn finish(&self, reason: Finish) {
+        self.reason.set(Some(reason));
+        if let Some(ticker) = &*self.ticker.borrow() {
+            ticker.stop();
+        }
+    }
+}
+
+/// Ticker of [TickerFactory::Output] stopping itself once any of its [Bounds] is reached,
+/// e.g. after a number of ticks, a deadline, or a predicate.
+///
+/// Counters restart on each start, and [Bounded::completion] resolves when the run ends.
+///
+/// ```ignore
+/// let ticker = Bounded::<AutoTickerFactory>::new(Bounds::new().max_ticks(1000), || ())?;
+/// ticker.start()?;
+/// assert_eq!(ticker.completion().await, Ok(Finish::MaxTicks));
+/// ```
+pub struct Bounded<F: TickerFactory> {
+    ticker: F::Output,
+    shared: Rc<Shared<F::Output>>,
+}
+
+impl<F: TickerFactory> Bounded<F>
+where
+    F::Output: 'static,
+{
+    pub fn new(bounds: Bounds, mut task: impl FnMut() + 'static) -> Result<Self, TickerError> {
+        let shared = Rc::new(Shared {
+            bounds: WasmRefCell::new(bounds),
+            ticks: Cell::new(0),
+            started: Cell::new(None),
+            reason: Cell::new(None),
+            run: WasmRefCell::new(Rc::new(CompletionCell::new())),
+            ticker: WasmRefCell::new(None),
+        });
+
+        let shared_ = shared.clone();
+        let ticker = F::new(move || {
+            let shared = &shared_;
+            if shared.expired() {
+                shared.finish(Finish::Deadline);
+                return;
+            }
+            task();
+            let ticks = shared.ticks.get() + 1;
+            shared.ticks.set(ticks);
+            if matches!(shared.bounds.borrow().max_ticks, Some(max) if ticks >= max) {
+                shared.finish(Finish::MaxTicks);
+            } else if shared.expired() {
+                shared.finish(Finish::Deadline);
+            } else if shared.predicate() {
+                shared.finish(Finish::Predicate);
+            }
+        })?;
+
+        let shared_ = shared.clone();
+        ticker.on_state_change(Box::new(move |state| {
+            let run = shared_.run.borrow().clone();
+            match state {
+                State::Started => {
+                    // Completions taken before first start resolve with first run
+                    if run.is_finished() {
+                        *shared_.run.borrow_mut() = Rc::new(CompletionCell::new());
+                    }
+                    shared_.ticks.set(0);
+                    shared_.started.set(Some(Instant::now()));
+                    shared_.reason.set(None);
+                }
+                State::Stopped => {
+                    let reason = shared_.reason.take().unwrap_or(Finish::Stopped);
+                    run.finish(Ok(reason));
+                }
+                State::Error(e) => run.finish(Err(e.clone())),
+            }
+        }));
+
+        shared.ticker.borrow_mut().replace(ticker.clone());
+        Ok(Self { ticker, shared })
+    }
+
+    /// Underlying ticker.
+    pub fn ticker(&self) -> &F::Output {
+        &self.ticker
+    }
+
+    /// Ticks of current or last run.
+    pub fn ticks(&self) -> u64 {
+        self.shared.ticks.get()
+    }
+
+    /// Replace bounds, effective from next tick.
+    pub fn set_bounds(&self, bounds: Bounds) {
+        *self.shared.bounds.borrow_mut() = bounds;
+    }
+
+    /// Resolve when current run ends, or at once with result of last run if stopped.
+    /// Before first start, resolve when first run ends.
+    pub fn completion(&self) -> Completion {
+        Completion {
+            run: self.shared.run.borrow().clone(),
+        }
+    }
+}
+
+impl<F: TickerFactory> Ticker for Bounded<F>
+where
+    F::Output: 'static,
+{
+    fn state(&self) -> State {
+        self.ticker.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        self.ticker.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        self.ticker.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.ticker.stop()
+    }
+
+    fn reset(&self) {
+        self.ticker.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.ticker.on_state_change(observer)
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        self.ticker.abort_signal()
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        F::Output::spawn(task)
+    }
+}
+
+impl<F: TickerFactory> Drop for Bounded<F> {
+    fn drop(&mut self) {
+        self.ticker.stop();
+        // Break reference cycle of the ticker stopping itself
+        self.shared.ticker.borrow_mut().take();
+    }
+}
+
+/// Future returned by [Bounded::completion], resolving with why the run ended,
+/// or the error stopping it.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Completion {
+    run: Rc<Run>,
+}
+
+impl Future for Completion {
+    type Output = Result<Finish, TickerError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.run.poll_clone(cx)
+    }
+}
@@ -0,0 +1,251 @@
+use crate::bindings::{now, promise_resolvers};
+use crate::completion::CompletionCell;
+use crate::ticker::BoxedTicker;
+use crate::{State, Ticker, TickerError, TickerFactory};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::JsValue;
+
+/// Items processed per tick.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChunkSize {
+    /// Fixed count of items, at least one.
+    Items(usize),
+    /// As many items as fit in the time budget, sized by measured cost of previous chunks.
+    Budget(Duration),
+}
+
+/// Reported after each chunk, see [Chunked::on_progress].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Progress {
+    /// Items processed so far.
+    pub processed: u64,
+    /// Total items if known exactly from [Iterator::size_hint].
+    pub total: Option<u64>,
+}
+
+type ProgressFn = Box<dyn FnMut(Progress)>;
+
+struct Shared<T> {
+    processed: Cell<u64>,
+    total: Option<u64>,
+    progress: WasmRefCell<Option<ProgressFn>>,
+    completion: CompletionCell<Result<T, TickerError>>,
+}
+
+impl<T> Shared<T> {
+    fn report(&self) {
+        // Not borrowed while calling, the callback may replace itself
+        let progress = self.progress.borrow_mut().take();
+        if let Some(mut progress) = progress {
+            progress(Progress {
+                processed: self.processed.get(),
+                total: self.total,
+            });
+            let mut current = self.progress.borrow_mut();
+            if current.is_none() {
+                *current = Some(progress);
+            }
+        }
+    }
+}
+
+/// Chunk size for next tick by [ChunkSize], adapting to measured cost.
+struct Sizer {
+    size: ChunkSize,
+    next: usize,
+}
+
+impl Sizer {
+    fn new(size: ChunkSize) -> Self {
+        let next = match size {
+            ChunkSize::Items(n) => n.max(1),
+            ChunkSize::Budget(_) => 1,
+        };
+        Self { size, next }
+    }
+
+    /// Adapt to `elapsed` milliseconds of a chunk of `n` items.
+    fn measure(&mut self, n: usize, elapsed: f64) {
+        if let ChunkSize::Budget(budget) = self.size {
+            let budget = budget.as_secs_f64() * 1000.0;
+            let estimated = if elapsed > 0.0 {
+                (n as f64 * budget / elapsed) as usize
+            } else {
+                usize::MAX
+            };
+            // Grow at most twice per tick, a chunk may be cheap by chance
+            self.next = estimated.clamp(1, n.saturating_mul(2).max(1));
+        }
+    }
+}
+
+/// Handle of work spread across ticks by [for_each_chunked] or [fold_chunked].
+///
+/// Resolves with the result once the iterator is exhausted,
+/// [TickerError::Cancelled] after [Chunked::cancel], or the error of the ticker.
+/// Work continues if the handle is dropped.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Chunked<T> {
+    shared: Rc<Shared<T>>,
+}
+
+impl<T: 'static> Chunked<T> {
+    /// Call `progress` after each chunk.
+    pub fn on_progress(&self, progress: impl FnMut(Progress) + 'static) {
+        self.shared
+            .progress
+            .borrow_mut()
+            .replace(Box::new(progress));
+    }
+
+    /// Items processed so far.
+    pub fn processed(&self) -> u64 {
+        self.shared.processed.get()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.shared.completion.is_finished()
+    }
+
+    /// Stop processing, resolving with [TickerError::Cancelled] if not finished.
+    pub fn cancel(&self) {
+        self.shared.completion.finish(Err(TickerError::Cancelled));
+    }
+
+    /// Wrap result by [Promise](js_sys::Promise).
+    ///
+    /// Requires [`Promise.withResolvers`][withResolvers] method.
+    ///
+    /// [withResolvers]: https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
+    pub fn into_promise(self) -> Result<js_sys::Promise, TickerError>
+    where
+        T: Into<JsValue>,
+    {
+        let resolvers = promise_resolvers()?;
+        let promise = resolvers.__wasm_ticker_binding_promise();
+        let resolve = resolvers.__wasm_ticker_binding_resolve();
+        let reject = resolvers.__wasm_ticker_binding_reject();
+        let settle = move |result: Result<T, TickerError>| {
+            match result {
+                Ok(r) => resolve.call1(&JsValue::null(), &r.into()),
+                Err(e) => reject.call1(&JsValue::null(), &e.into()),
+            }
+            .unwrap();
+        };
+        self.shared.completion.on_done(settle);
+        Ok(promise)
+    }
+}
+
+impl<T> Future for Chunked<T> {
+    type Output = Result<T, TickerError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = self.shared.completion.poll_take(cx);
+        result.map(|result| result.unwrap_or(Err(TickerError::Cancelled)))
+    }
+}
+
+/// Fold `iter` by `f` across ticks of [TickerFactory::Output], a chunk of items per tick.
+///
+/// Processing starts on next tick.
+///
+/// ```ignore
+/// let sum = fold_chunked::<AutoTickerFactory, _, _, _>(
+///     0..1_000_000u64,
+///     ChunkSize::Budget(Duration::from_millis(8)),
+///     0,
+///     |sum, i| sum + i,
+/// )?;
+/// sum.on_progress(|p| log(p.processed));
+/// assert_eq!(sum.await?, 499999500000);
+/// ```
+pub fn fold_chunked<F, I, B, G>(
+    iter: I,
+    size: ChunkSize,
+    init: B,
+    mut f: G,
+) -> Result<Chunked<B>, TickerError>
+where
+    F: TickerFactory,
+    F::Output: 'static,
+    I: IntoIterator,
+    I::IntoIter: 'static,
+    B: 'static,
+    G: FnMut(B, I::Item) -> B + 'static,
+{
+    let mut iter = iter.into_iter();
+    let total = match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(lower as u64),
+        _ => None,
+    };
+    let shared = Rc::new(Shared {
+        processed: Cell::new(0),
+        total,
+        progress: WasmRefCell::new(None),
+        completion: CompletionCell::new(),
+    });
+
+    let shared_ = shared.clone();
+    let mut acc = Some(init);
+    let mut sizer = Sizer::new(size);
+    let ticker = F::new(move || {
+        let mut value = match acc.take() {
+            Some(value) => value,
+            None => return,
+        };
+        let start = now();
+        let mut n = 0;
+        let mut exhausted = false;
+        while n < sizer.next {
+            match iter.next() {
+                Some(item) => value = f(value, item),
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+            n += 1;
+        }
+        sizer.measure(n, now() - start);
+        shared_.processed.set(shared_.processed.get() + n as u64);
+        shared_.report();
+        if exhausted {
+            shared_.completion.finish(Ok(value));
+        } else if !shared_.completion.is_finished() {
+            acc = Some(value);
+        }
+    })?;
+
+    let shared_ = shared.clone();
+    ticker.on_state_change(Box::new(move |state| {
+        if let State::Error(e) = state {
+            shared_.completion.finish(Err(e.clone()));
+        }
+    }));
+    ticker.start()?;
+    shared.completion.set_ticker(BoxedTicker::new(ticker));
+    Ok(Chunked { shared })
+}
+
+/// Call `f` on each item of `iter` across ticks, see [fold_chunked].
+pub fn for_each_chunked<F, I, G>(
+    iter: I,
+    size: ChunkSize,
+    mut f: G,
+) -> Result<Chunked<()>, TickerError>
+where
+    F: TickerFactory,
+    F::Output: 'static,
+    I: IntoIterator,
+    I::IntoIter: 'static,
+    G: FnMut(I::Item) + 'static,
+{
+    fold_chunked::<F, _, _, _>(iter, size, (), move |(), item| f(item))
+}
@@ -0,0 +1,110 @@
+use crate::ticker::BoxedTicker;
+use crate::Ticker;
+use std::cell::Cell;
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen::__rt::WasmRefCell;
+
+type DoneFn<T> = Box<dyn FnOnce(T)>;
+
+/// Result of work driven by a ticker, waking futures polling for it.
+pub(crate) struct CompletionCell<T> {
+    /// Clone of ticker to stop itself, dropped when finished.
+    ticker: WasmRefCell<Option<BoxedTicker>>,
+    result: WasmRefCell<Option<T>>,
+    wakers: WasmRefCell<Vec<Waker>>,
+    /// Called with the result instead of storing it, e.g. settling a promise.
+    on_done: WasmRefCell<Option<DoneFn<T>>>,
+    finished: Cell<bool>,
+}
+
+impl<T> CompletionCell<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            ticker: WasmRefCell::new(None),
+            result: WasmRefCell::new(None),
+            wakers: WasmRefCell::new(Vec::new()),
+            on_done: WasmRefCell::new(None),
+            finished: Cell::new(false),
+        }
+    }
+
+    /// Stop `ticker` once finished.
+    pub(crate) fn set_ticker(&self, ticker: BoxedTicker) {
+        self.ticker.borrow_mut().replace(ticker);
+    }
+
+    /// Drop the ticker without stopping it, breaking the cycle of a ticker stopping itself.
+    pub(crate) fn release(&self) {
+        self.ticker.borrow_mut().take();
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished.get()
+    }
+
+    /// Stop the ticker, and store `result` waking futures or pass it to [CompletionCell::on_done].
+    ///
+    /// Only first call takes effect.
+    pub(crate) fn finish(&self, result: T) {
+        if self.finished.replace(true) {
+            return;
+        }
+        // Not borrowed while stopping, observers may finish again
+        let ticker = self.ticker.borrow_mut().take();
+        if let Some(ticker) = ticker {
+            ticker.stop();
+        }
+        let on_done = self.on_done.borrow_mut().take();
+        match on_done {
+            Some(on_done) => on_done(result),
+            None => {
+                self.result.borrow_mut().replace(result);
+                for waker in std::mem::take(&mut *self.wakers.borrow_mut()) {
+                    waker.wake();
+                }
+            }
+        }
+    }
+
+    /// Call `f` with the result once finished, at once if stored already.
+    pub(crate) fn on_done(&self, f: impl FnOnce(T) + 'static) {
+        let result = self.result.borrow_mut().take();
+        match result {
+            Some(result) => f(result),
+            None => {
+                self.on_done.borrow_mut().replace(Box::new(f));
+            }
+        }
+    }
+
+    /// Take the result for a single future, [None] once taken.
+    pub(crate) fn poll_take(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match self.result.borrow_mut().take() {
+            Some(result) => Poll::Ready(Some(result)),
+            None if self.finished.get() => Poll::Ready(None),
+            None => {
+                self.register(cx.waker());
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Clone the result, for any number of futures.
+    pub(crate) fn poll_clone(&self, cx: &mut Context<'_>) -> Poll<T>
+    where
+        T: Clone,
+    {
+        if let Some(result) = &*self.result.borrow() {
+            return Poll::Ready(result.clone());
+        }
+        self.register(cx.waker());
+        Poll::Pending
+    }
+
+    fn register(&self, waker: &Waker) {
+        let mut wakers = self.wakers.borrow_mut();
+        if !wakers.iter().any(|w| w.will_wake(waker)) {
+            wakers.push(waker.clone());
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::completion::CompletionCell;
+use crate::ticker::BoxedTicker;
+use crate::{State, Ticker, TickerError, TickerFactory};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use web_sys::AbortSignal;
+
+/// Wakes coroutine awaiting other futures than [Yielder::yield_tick].
+struct Woken(AtomicBool);
+
+impl Wake for Woken {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Passed to body of [Coroutine] to suspend until next tick.
+#[derive(Clone)]
+pub struct Yielder {
+    yielded: Rc<Cell<bool>>,
+    ticks: Rc<Cell<u64>>,
+}
+
+impl Yielder {
+    /// Suspend until next tick of the driving ticker.
+    pub fn yield_tick(&self) -> YieldTick {
+        YieldTick {
+            yielded: self.yielded.clone(),
+            suspended: false,
+        }
+    }
+
+    /// Ticks the body was resumed on so far.
+    pub fn ticks(&self) -> u64 {
+        self.ticks.get()
+    }
+}
+
+/// Future returned by [Yielder::yield_tick].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct YieldTick {
+    yielded: Rc<Cell<bool>>,
+    suspended: bool,
+}
+
+impl Future for YieldTick {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.suspended {
+            return Poll::Ready(());
+        }
+        self.suspended = true;
+        self.yielded.set(true);
+        Poll::Pending
+    }
+}
+
+/// Async body resumed on each tick of [TickerFactory::Output],
+/// for spreading a loop across ticks while keeping local variables.
+///
+/// The body suspends at `yielder.yield_tick().await`, and runs only while started,
+/// so [Ticker::stop] pauses it and [Ticker::start] resumes it.
+/// Other futures may be awaited too, the body is resumed on the first tick after they wake it.
+///
+/// Awaiting the coroutine, e.g. by `(&mut coroutine).await`, resolves with output of the body,
+/// or with the error of the ticker, like [TickerError::CallbackPanicked] if the body panicked.
+///
+/// ```ignore
+/// let coroutine = Coroutine::<AnimationFrameTickerFactory, _>::new(|yielder| async move {
+///     let mut sum = 0;
+///     for row in rows {
+///         sum += process(row);
+///         yielder.yield_tick().await;
+///     }
+///     sum
+/// })?;
+/// coroutine.start()?;
+/// let sum = coroutine.await?;
+/// ```
+pub struct Coroutine<F: TickerFactory, T> {
+    ticker: F::Output,
+    completion: Rc<CompletionCell<Result<T, TickerError>>>,
+}
+
+impl<F: TickerFactory, T: 'static> Coroutine<F, T>
+where
+    F::Output: 'static,
+{
+    pub fn new<B, Fut>(body: B) -> Result<Self, TickerError>
+    where
+        B: FnOnce(Yielder) -> Fut,
+        Fut: Future<Output = T> + 'static,
+    {
+        let completion = Rc::new(CompletionCell::new());
+        let yielder = Yielder {
+            yielded: Rc::new(Cell::new(false)),
+            ticks: Rc::new(Cell::new(0)),
+        };
+        let yielded = yielder.yielded.clone();
+        let ticks = yielder.ticks.clone();
+        let mut future: Option<Pin<Box<dyn Future<Output = T>>>> = Some(Box::pin(body(yielder)));
+        // Poll on first tick
+        let woken = Arc::new(Woken(AtomicBool::new(true)));
+        let waker = Waker::from(woken.clone());
+
+        let completion_ = completion.clone();
+        let ticker = F::new(move || {
+            let body = match &mut future {
+                Some(body) => body,
+                None => return,
+            };
+            // Not yielded means waiting for another future
+            if !yielded.replace(false) && !woken.0.swap(false, Ordering::SeqCst) {
+                return;
+            }
+            ticks.set(ticks.get() + 1);
+            if let Poll::Ready(result) = body.as_mut().poll(&mut Context::from_waker(&waker)) {
+                future = None;
+                completion_.finish(Ok(result));
+            }
+        })?;
+
+        let completion_ = completion.clone();
+        ticker.on_state_change(Box::new(move |state| {
+            if let State::Error(e) = state {
+                completion_.finish(Err(e.clone()));
+            }
+        }));
+        completion.set_ticker(BoxedTicker::new(ticker.clone()));
+        Ok(Self { ticker, completion })
+    }
+
+    /// Underlying ticker.
+    pub fn ticker(&self) -> &F::Output {
+        &self.ticker
+    }
+
+    /// Whether the body returned, or the ticker failed.
+    pub fn is_finished(&self) -> bool {
+        self.completion.is_finished()
+    }
+}
+
+impl<F: TickerFactory, T> Ticker for Coroutine<F, T> {
+    fn state(&self) -> State {
+        self.ticker.state()
+    }
+
+    /// Resume the body from next tick, does nothing if finished.
+    fn start(&self) -> Result<(), TickerError> {
+        if self.completion.is_finished() {
+            return Ok(());
+        }
+        self.ticker.start()
+    }
+
+    /// Resume the body at once, does nothing if finished.
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        if self.completion.is_finished() {
+            return Ok(());
+        }
+        self.ticker.start_immediate()
+    }
+
+    /// Pause the body at its current suspension point.
+    fn stop(&self) {
+        self.ticker.stop()
+    }
+
+    fn reset(&self) {
+        self.ticker.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.ticker.on_state_change(observer)
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        self.ticker.abort_signal()
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        F::Output::spawn(task)
+    }
+}
+
+impl<F: TickerFactory, T> Unpin for Coroutine<F, T> {}
+
+impl<F: TickerFactory, T> Future for Coroutine<F, T> {
+    type Output = Result<T, TickerError>;
+
+    /// Pending until the body returns or the ticker fails, output can be taken once.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.completion.poll_take(cx) {
+            Poll::Ready(Some(result)) => Poll::Ready(result),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<F: TickerFactory, T> Drop for Coroutine<F, T> {
+    fn drop(&mut self) {
+        self.ticker.stop();
+        // Break reference cycle of the ticker stopping itself
+        self.completion.release();
+    }
+}
@@ -0,0 +1,315 @@
+use crate::bindings::{
+    __wasm_ticker_binding_cancel_animation_frame as cancelAnimationFrame,
+    __wasm_ticker_binding_clear_timeout as clearTimeout,
+    __wasm_ticker_binding_request_animation_frame as requestAnimationFrame,
+    __wasm_ticker_binding_set_timeout_with_delay as setTimeout, has_request_animation_frame, now,
+    require_js, AnimationFrameToken, TimeoutToken,
+};
+use crate::TickerError;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+
+/// Interval of [Throttled::per_frame] if `requestAnimationFrame` is not available.
+const FALLBACK_FRAME: Duration = Duration::from_millis(16);
+
+/// Options of [Debounced].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DebounceOptions {
+    /// Quiet time after last call before trailing edge.
+    pub wait: Duration,
+    /// Call on first call of a burst.
+    pub leading: bool,
+    /// Call with latest argument after the burst.
+    pub trailing: bool,
+    /// Call at least this often during a continuous burst.
+    pub max_wait: Option<Duration>,
+}
+
+impl DebounceOptions {
+    /// Trailing edge only, without max wait.
+    pub fn new(wait: Duration) -> Self {
+        Self {
+            wait,
+            leading: false,
+            trailing: true,
+            max_wait: None,
+        }
+    }
+}
+
+enum Token {
+    Timeout(TimeoutToken),
+    Frame(AnimationFrameToken),
+}
+
+struct Inner<F, A> {
+    f: WasmRefCell<F>,
+    options: DebounceOptions,
+    /// Wait for next animation frame instead of [DebounceOptions::wait].
+    frame: bool,
+    pending: WasmRefCell<Option<A>>,
+    /// Whether `f` is running, calls from within it are kept pending.
+    calling: Cell<bool>,
+    last_call: Cell<f64>,
+    burst_start: Cell<f64>,
+    token: WasmRefCell<Option<Token>>,
+    cb: WasmRefCell<Option<Closure<dyn FnMut()>>>,
+}
+
+impl<F: FnMut(A) + 'static, A: 'static> Inner<F, A> {
+    fn arm(self: &Rc<Self>, delay: f64) -> Result<(), TickerError> {
+        require_js("setTimeout")?;
+        let mut cb = self.cb.borrow_mut();
+        let cb = cb.get_or_insert_with(|| {
+            let this = Rc::downgrade(self);
+            Closure::new(move || {
+                if let Some(this) = this.upgrade() {
+                    this.fire();
+                }
+            })
+        });
+        let cb = cb.as_ref().unchecked_ref();
+        let token = if self.frame {
+            Token::Frame(requestAnimationFrame(cb).map_err(TickerError::ScheduleFailed)?)
+        } else {
+            let delay = delay.ceil().max(0.0);
+            Token::Timeout(setTimeout(cb, delay).map_err(TickerError::ScheduleFailed)?)
+        };
+        self.token.borrow_mut().replace(token);
+        Ok(())
+    }
+
+    fn cancel(&self) {
+        match self.token.borrow_mut().take() {
+            Some(Token::Timeout(token)) => clearTimeout(token),
+            Some(Token::Frame(token)) => cancelAnimationFrame(token),
+            None => (),
+        }
+    }
+
+    /// Call `f`, or keep `arg` pending if called from within `f`, returning whether called.
+    fn invoke(&self, arg: A) -> bool {
+        if self.calling.replace(true) {
+            self.pending.borrow_mut().replace(arg);
+            return false;
+        }
+        (self.f.borrow_mut())(arg);
+        self.calling.set(false);
+        true
+    }
+
+    fn call(self: &Rc<Self>, arg: A) -> Result<(), TickerError> {
+        let now = now();
+        self.last_call.set(now);
+        if self.token.borrow().is_some() {
+            self.pending.borrow_mut().replace(arg);
+            return Ok(());
+        }
+        self.burst_start.set(now);
+        self.arm(millis(self.options.wait))?;
+        if self.options.leading {
+            self.pending.borrow_mut().take();
+            self.invoke(arg);
+        } else {
+            self.pending.borrow_mut().replace(arg);
+        }
+        Ok(())
+    }
+
+    fn fire(self: &Rc<Self>) {
+        self.token.borrow_mut().take();
+        let now = now();
+        if !self.frame {
+            let wait = millis(self.options.wait) - (now - self.last_call.get());
+            let max_wait = match self.options.max_wait {
+                Some(max_wait) => millis(max_wait) - (now - self.burst_start.get()),
+                None => f64::INFINITY,
+            };
+            // Called again since armed
+            if wait > 0.0 && max_wait > 0.0 && self.arm(wait.min(max_wait)).is_ok() {
+                return;
+            }
+        }
+        let called = self.trailing();
+        // Keep throttling calls right after the trailing edge, unless called again by `f`
+        if called && self.options.max_wait.is_some() && self.token.borrow().is_none() {
+            self.burst_start.set(now);
+            self.last_call.set(now);
+            let _ = self.arm(millis(self.options.wait));
+        }
+    }
+
+    /// Call with pending argument, returning whether called.
+    fn trailing(&self) -> bool {
+        let arg = self.pending.borrow_mut().take();
+        match arg {
+            Some(arg) if self.options.trailing => self.invoke(arg),
+            _ => false,
+        }
+    }
+}
+
+impl<F, A> Drop for Inner<F, A> {
+    fn drop(&mut self) {
+        match self.token.borrow_mut().take() {
+            Some(Token::Timeout(token)) => clearTimeout(token),
+            Some(Token::Frame(token)) => cancelAnimationFrame(token),
+            None => (),
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Callback delayed until calls stop for [DebounceOptions::wait], waiting by `setTimeout`.
+///
+/// Only latest argument of a burst of calls is passed on trailing edge,
+/// a call from within the callback is deferred to trailing edge of a new burst.
+/// Pending timer is cleared when all clones are dropped.
+///
+/// ```ignore
+/// let search = Debounced::new(DebounceOptions::new(Duration::from_millis(300)), |query: String| {
+///     // ...
+/// });
+/// search.call(input.value())?;
+/// ```
+pub struct Debounced<F, A = ()> {
+    inner: Rc<Inner<F, A>>,
+}
+
+impl<F: FnMut(A) + 'static, A: 'static> Debounced<F, A> {
+    pub fn new(options: DebounceOptions, f: F) -> Self {
+        Self::with_frame(options, false, f)
+    }
+
+    fn with_frame(options: DebounceOptions, frame: bool, f: F) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                f: WasmRefCell::new(f),
+                options,
+                frame,
+                pending: WasmRefCell::new(None),
+                calling: Cell::new(false),
+                last_call: Cell::new(0.0),
+                burst_start: Cell::new(0.0),
+                token: WasmRefCell::new(None),
+                cb: WasmRefCell::new(None),
+            }),
+        }
+    }
+
+    pub fn options(&self) -> DebounceOptions {
+        self.inner.options
+    }
+
+    /// Call on leading edge, or store `arg` for trailing edge.
+    pub fn call(&self, arg: A) -> Result<(), TickerError> {
+        self.inner.call(arg)
+    }
+
+    /// Call with pending argument at once if trailing edge enabled, and end the burst.
+    pub fn flush(&self) {
+        self.inner.cancel();
+        self.inner.trailing();
+    }
+
+    /// Drop pending argument and end the burst without calling.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+        self.inner.pending.borrow_mut().take();
+    }
+
+    /// Whether a burst is in progress, waiting for its timer.
+    pub fn is_pending(&self) -> bool {
+        self.inner.token.borrow().is_some()
+    }
+}
+
+impl<F, A> Clone for Debounced<F, A> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Callback called at most once per `wait`, waiting by `setTimeout`,
+/// or once per animation frame by [Throttled::per_frame].
+///
+/// Calls between are merged, passing latest argument on trailing edge.
+pub struct Throttled<F, A = ()> {
+    debounced: Debounced<F, A>,
+}
+
+impl<F: FnMut(A) + 'static, A: 'static> Throttled<F, A> {
+    /// Call on both leading and trailing edges.
+    pub fn new(wait: Duration, f: F) -> Self {
+        Self::with_edges(wait, true, true, f)
+    }
+
+    pub fn with_edges(wait: Duration, leading: bool, trailing: bool, f: F) -> Self {
+        let options = DebounceOptions {
+            wait,
+            leading,
+            trailing,
+            max_wait: Some(wait),
+        };
+        Self {
+            debounced: Debounced::new(options, f),
+        }
+    }
+
+    /// Call once on next animation frame with latest argument,
+    /// by `setTimeout` of 16ms if `requestAnimationFrame` is not available.
+    pub fn per_frame(f: F) -> Self {
+        if !has_request_animation_frame() {
+            return Self::with_edges(FALLBACK_FRAME, false, true, f);
+        }
+        let options = DebounceOptions {
+            wait: Duration::ZERO,
+            leading: false,
+            trailing: true,
+            max_wait: None,
+        };
+        Self {
+            debounced: Debounced::with_frame(options, true, f),
+        }
+    }
+
+    pub fn options(&self) -> DebounceOptions {
+        self.debounced.options()
+    }
+
+    /// Call on leading edge, or store `arg` for trailing edge.
+    pub fn call(&self, arg: A) -> Result<(), TickerError> {
+        self.debounced.call(arg)
+    }
+
+    /// Call with pending argument at once if trailing edge enabled, and end the period.
+    pub fn flush(&self) {
+        self.debounced.flush()
+    }
+
+    /// Drop pending argument and end the period without calling.
+    pub fn cancel(&self) {
+        self.debounced.cancel()
+    }
+
+    /// Whether a period is in progress, waiting for its timer or frame.
+    pub fn is_pending(&self) -> bool {
+        self.debounced.is_pending()
+    }
+}
+
+impl<F, A> Clone for Throttled<F, A> {
+    fn clone(&self) -> Self {
+        Self {
+            debounced: self.debounced.clone(),
+        }
+    }
+}
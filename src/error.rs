@@ -0,0 +1,70 @@
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+/// Error of [Ticker](crate::Ticker) and [TickerFactory](crate::TickerFactory),
+/// also carried by [State::Error](crate::State::Error).
+#[derive(Clone, PartialEq, Debug)]
+pub enum TickerError {
+    /// Ticker is started already.
+    AlreadyStarted,
+    /// No backend is available on current JavaScript Runtime.
+    NoBackendAvailable,
+    /// No backend is registered by the name in [TickerRegistry](crate::factory::TickerRegistry).
+    NotRegistered(String),
+    /// Required JavaScript API of the backend is not available.
+    Unavailable(&'static str),
+    /// Scheduling API threw, like `setTimeout` or `postMessage`.
+    ScheduleFailed(JsValue),
+    /// Received a `messageerror` event by [MessageChannelTicker](crate::ticker::MessageChannelTicker).
+    MessageError(JsValue),
+    /// Task panicked, only detectable on native targets where panics unwind.
+    CallbackPanicked,
+    /// Work was cancelled before completion.
+    Cancelled,
+    /// Other JavaScript exception.
+    Js(JsValue),
+}
+
+impl fmt::Display for TickerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickerError::AlreadyStarted => f.write_str("ticker started"),
+            TickerError::NoBackendAvailable => f.write_str("no available implementation detected"),
+            TickerError::NotRegistered(name) => write!(f, "backend not registered: {}", name),
+            TickerError::Unavailable(api) => write!(f, "required API not available: {}", api),
+            TickerError::ScheduleFailed(e) => write!(f, "failed to schedule task: {:?}", e),
+            TickerError::MessageError(e) => write!(f, "message error: {:?}", e),
+            TickerError::CallbackPanicked => f.write_str("task panicked"),
+            TickerError::Cancelled => f.write_str("cancelled"),
+            TickerError::Js(e) => write!(f, "JavaScript exception: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for TickerError {}
+
+impl From<JsValue> for TickerError {
+    fn from(value: JsValue) -> Self {
+        TickerError::Js(value)
+    }
+}
+
+impl From<TickerError> for JsValue {
+    fn from(value: TickerError) -> Self {
+        match value {
+            TickerError::AlreadyStarted => JsValue::from_str("Ticker started"),
+            TickerError::NoBackendAvailable => {
+                JsValue::from_str("No available implementation detected")
+            }
+            TickerError::NotRegistered(name) => {
+                JsValue::from_str(&format!("Backend not registered: {}", name))
+            }
+            TickerError::Unavailable(api) => {
+                JsValue::from_str(&format!("Required API not available: {}", api))
+            }
+            TickerError::ScheduleFailed(e) | TickerError::MessageError(e) | TickerError::Js(e) => e,
+            TickerError::CallbackPanicked => JsValue::from_str("Task panicked"),
+            TickerError::Cancelled => JsValue::from_str("Cancelled"),
+        }
+    }
+}
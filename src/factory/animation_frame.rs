@@ -0,0 +1,72 @@
+use super::reschedule;
+use crate::backend::RequestAnimationFrame;
+use crate::bindings::__wasm_ticker_binding_performance_now as now;
+use crate::state::StateCell;
+use crate::ticker::{scheduler, AnimationFrameTicker, BackendTicker, FramePacing, FunctionTicker};
+use crate::{State, TickerError, TickerFactory};
+use std::rc::{Rc, Weak};
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+
+/// Factory type for [AnimationFrameTicker].
+#[derive(Clone, Copy)]
+pub struct AnimationFrameTickerFactory;
+
+impl AnimationFrameTickerFactory {
+    /// Construct [AnimationFrameTicker] skipping frames to tick at most
+    /// `target_fps` times per second, e.g. 30 or 60 on high refresh rate displays.
+    ///
+    /// Frames are picked by timestamp passed to `requestAnimationFrame` callback.
+    /// See also [AnimationFrameTicker::set_target_fps].
+    pub fn new_with_target_fps(
+        target_fps: Option<f64>,
+        mut task: impl FnMut() + 'static,
+    ) -> Result<AnimationFrameTicker, TickerError> {
+        let state = Rc::new(StateCell::new());
+        let token = Rc::new(WasmRefCell::new(None));
+        let pacing = Rc::new(WasmRefCell::new(FramePacing::new(target_fps)));
+
+        let state_ = state.clone();
+        let token_ = token.clone();
+        let pacing_ = pacing.clone();
+        let cb = Rc::new_cyclic(move |weak: &Weak<Closure<dyn FnMut(JsValue)>>| {
+            let weak = weak.clone();
+            Closure::new(move |timestamp: JsValue| {
+                if state_.borrow().ne(&State::Started) {
+                    return;
+                }
+                // Called without timestamp by `start_immediate`
+                let timestamp = timestamp.as_f64().unwrap_or_else(now);
+                if pacing_.borrow_mut().frame(timestamp) {
+                    task();
+                    // Task may have stopped the ticker
+                    if state_.borrow().ne(&State::Started) {
+                        return;
+                    }
+                }
+                reschedule::<RequestAnimationFrame>(&weak, &token_, &state_);
+            })
+        });
+        Ok(AnimationFrameTicker {
+            inner: BackendTicker { token, state, cb },
+            pacing,
+        })
+    }
+}
+
+impl TickerFactory for AnimationFrameTickerFactory {
+    type Output = AnimationFrameTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        Self::new_with_target_fps(None, task)
+    }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        let scheduler = scheduler::<RequestAnimationFrame>();
+        FunctionTicker::with_scheduler(scheduler, Self::new, function, this, args)
+    }
+}
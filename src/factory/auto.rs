@@ -1,28 +1,73 @@
-use super::{message_channel::MessageChannelTickerFactory, timers::*};
+use super::{
+    animation_frame::AnimationFrameTickerFactory, message_channel::MessageChannelTickerFactory,
+    timers::*,
+};
+use crate::backend::{RequestAnimationFrame, SetImmediate, SetTimeout};
+use crate::bindings::__wasm_ticker_message_channel_scheduler;
 use crate::ticker::*;
-use crate::{NamedTicker, TickerFactory};
-use wasm_bindgen::JsValue;
+use crate::{NamedTicker, Runtime, TickerError, TickerFactory};
 use wasm_bindgen::__rt::Lazy;
+use wasm_bindgen::JsValue;
 
+#[derive(Clone, Copy)]
 pub(crate) enum SelectedTicker {
     MessageChannel,
     Immediate,
     Timeout,
     AnimationFrame,
+    #[cfg(not(target_family = "wasm"))]
+    Native,
     None,
 }
 
+impl SelectedTicker {
+    /// Candidates in order of preference on `runtime`.
+    pub(crate) fn preference(runtime: Runtime) -> &'static [SelectedTicker] {
+        use SelectedTicker::*;
+        match runtime {
+            // `setImmediate` is native and faster than delivering port messages
+            Runtime::Bun => &[Immediate, MessageChannel, Timeout, AnimationFrame],
+            // `setImmediate` may be a polyfill by timers of Node compatibility layer
+            Runtime::Deno => &[MessageChannel, Timeout],
+            _ => &[MessageChannel, Immediate, Timeout, AnimationFrame],
+        }
+    }
+
+    /// Name in default [TickerRegistry](super::TickerRegistry), [None] if nothing is selected.
+    pub(crate) fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::MessageChannel => Some("message_channel"),
+            Self::Immediate => Some("immediate"),
+            Self::Timeout => Some("timeout"),
+            Self::AnimationFrame => Some("animation_frame"),
+            #[cfg(not(target_family = "wasm"))]
+            Self::Native => Some("native"),
+            Self::None => None,
+        }
+    }
+
+    fn check(&self) -> bool {
+        match self {
+            Self::MessageChannel => MessageChannelTicker::check(),
+            Self::Immediate => ImmediateTicker::check(),
+            Self::Timeout => TimeoutTicker::check(),
+            Self::AnimationFrame => AnimationFrameTicker::check(),
+            #[cfg(not(target_family = "wasm"))]
+            Self::Native => NativeTicker::check(),
+            Self::None => true,
+        }
+    }
+}
+
 pub(crate) static SELECTED_TICKER: Lazy<SelectedTicker> = Lazy::new(|| {
-    if MessageChannelTicker::check() {
-        SelectedTicker::MessageChannel
-    } else if ImmediateTicker::check() {
-        SelectedTicker::Immediate
-    } else if TimeoutTicker::check() {
-        SelectedTicker::Timeout
-    } else if AnimationFrameTicker::check() {
-        SelectedTicker::AnimationFrame
-    } else {
-        SelectedTicker::None
+    let selected = SelectedTicker::preference(Runtime::current())
+        .iter()
+        .find(|candidate| candidate.check());
+    match selected {
+        Some(selected) => *selected,
+        #[cfg(not(target_family = "wasm"))]
+        None if NativeTicker::check() => SelectedTicker::Native,
+        None => SelectedTicker::None,
     }
 });
 
@@ -34,14 +79,17 @@ pub(crate) static SELECTED_TICKER: Lazy<SelectedTicker> = Lazy::new(|| {
 /// - [ImmediateTicker]
 /// - [TimeoutTicker]
 /// - [AnimationFrameTicker]
+/// - [NativeTicker](crate::ticker::NativeTicker), only on native targets
+///
+/// [ImmediateTicker] goes first in Bun, and is skipped in Deno, see [Runtime].
 #[derive(Clone, Copy)]
 pub struct AutoTickerFactory;
 impl TickerFactory for AutoTickerFactory {
     type Output = AutoTicker;
 
-    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, JsValue> {
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
         match *SELECTED_TICKER {
-            SelectedTicker::None => Err(JsValue::from_str("No available implementation detected")),
+            SelectedTicker::None => Err(TickerError::NoBackendAvailable),
             SelectedTicker::MessageChannel => Result::map(
                 MessageChannelTickerFactory::new(task),
                 AutoTicker::MessageChannel,
@@ -56,6 +104,25 @@ impl TickerFactory for AutoTickerFactory {
                 AnimationFrameTickerFactory::new(task),
                 AutoTicker::AnimationFrame,
             ),
+            #[cfg(not(target_family = "wasm"))]
+            SelectedTicker::Native => {
+                Result::map(super::NativeTickerFactory::new(task), AutoTicker::Native)
+            }
         }
     }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        let scheduler = match *SELECTED_TICKER {
+            SelectedTicker::MessageChannel => Some(__wasm_ticker_message_channel_scheduler()?),
+            SelectedTicker::Immediate => scheduler::<SetImmediate>(),
+            SelectedTicker::Timeout => scheduler::<SetTimeout>(),
+            SelectedTicker::AnimationFrame => scheduler::<RequestAnimationFrame>(),
+            _ => None,
+        };
+        FunctionTicker::with_scheduler(scheduler, Self::new, function, this, args)
+    }
 }
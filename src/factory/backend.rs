@@ -0,0 +1,77 @@
+use crate::state::StateCell;
+use crate::ticker::{scheduler, BackendTicker, FunctionTicker};
+use crate::{ScheduleBackend, State, TickerError, TickerFactory};
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+
+/// Factory type for [BackendTicker] of any [ScheduleBackend].
+pub struct BackendTickerFactory<B: ScheduleBackend>(PhantomData<B>);
+
+impl<B: ScheduleBackend> Default for BackendTickerFactory<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: ScheduleBackend> Clone for BackendTickerFactory<B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B: ScheduleBackend> Copy for BackendTickerFactory<B> {}
+
+/// Reschedule `cb` after a tick, or record the error.
+pub(crate) fn reschedule<B: ScheduleBackend>(
+    cb: &Weak<Closure<dyn FnMut(JsValue)>>,
+    token: &WasmRefCell<Option<B::Token>>,
+    state: &StateCell,
+) {
+    if let Some(cb) = cb.upgrade() {
+        match B::schedule(cb.as_ref().as_ref().unchecked_ref()) {
+            Ok(t) => {
+                token.borrow_mut().replace(t);
+            }
+            Err(e) => {
+                state.set(State::Error(TickerError::ScheduleFailed(e)));
+            }
+        }
+    }
+}
+
+impl<B: ScheduleBackend> TickerFactory for BackendTickerFactory<B> {
+    type Output = BackendTicker<B>;
+
+    fn new(mut task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        let state = Rc::new(StateCell::new());
+        let token = Rc::new(WasmRefCell::new(None));
+
+        let state_ = state.clone();
+        let token_ = token.clone();
+        let cb = Rc::new_cyclic(move |weak: &Weak<Closure<dyn FnMut(JsValue)>>| {
+            let weak = weak.clone();
+            Closure::new(move |_: JsValue| {
+                if state_.borrow().eq(&State::Started) {
+                    task();
+                    // Task may have stopped the ticker
+                    if state_.borrow().ne(&State::Started) {
+                        return;
+                    }
+                    reschedule::<B>(&weak, &token_, &state_);
+                }
+            })
+        });
+        Ok(Self::Output { token, state, cb })
+    }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        let create = Self::new;
+        FunctionTicker::with_scheduler(scheduler::<B>(), create, function, this, args)
+    }
+}
@@ -0,0 +1,169 @@
+use super::{
+    AnimationFrameTickerFactory, ImmediateTickerFactory, MessageChannelTickerFactory,
+    SelectedTicker, TimeoutTickerFactory,
+};
+use crate::ticker::BoxedTicker;
+use crate::{NamedTicker, Runtime, Ticker, TickerError, TickerFactory};
+
+/// Object-safe counterpart of [TickerFactory],
+/// implemented for all factories constructing a [NamedTicker].
+///
+/// Implement this directly for custom backends which can't be constructed statically,
+/// and register them in [TickerRegistry].
+pub trait DynTickerFactory {
+    /// Check if constructed ticker is available on current JavaScript Runtime.
+    fn check(&self) -> bool;
+
+    /// Construct a ticker calling `task` on each tick.
+    fn create(&self, task: Box<dyn FnMut()>) -> Result<BoxedTicker, TickerError>;
+
+    /// Simply queue task once.
+    fn spawn(&self, task: Box<dyn FnOnce()>) -> Result<(), TickerError>;
+}
+
+impl<F> DynTickerFactory for F
+where
+    F: TickerFactory,
+    F::Output: NamedTicker + 'static,
+{
+    fn check(&self) -> bool {
+        F::Output::check()
+    }
+
+    fn create(&self, task: Box<dyn FnMut()>) -> Result<BoxedTicker, TickerError> {
+        F::new(task).map(BoxedTicker::new)
+    }
+
+    fn spawn(&self, task: Box<dyn FnOnce()>) -> Result<(), TickerError> {
+        F::Output::spawn(task)
+    }
+}
+
+/// Ordered, named set of [DynTickerFactory], selecting the first available one
+/// like [AutoTickerFactory](super::AutoTickerFactory).
+///
+/// [TickerRegistry::default] contains built-in backends
+/// `"message_channel"`, `"immediate"`, `"timeout"` and `"animation_frame"`
+/// in order of preference of [AutoTickerFactory](super::AutoTickerFactory) on current [Runtime],
+/// followed by `"native"` on native targets.
+pub struct TickerRegistry {
+    entries: Vec<(String, Box<dyn DynTickerFactory>)>,
+}
+
+impl TickerRegistry {
+    /// Registry without any backend, unlike [TickerRegistry::default].
+    pub fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registry without any backend.
+    #[deprecated(note = "renamed to `TickerRegistry::empty`")]
+    pub fn new() -> Self {
+        Self::empty()
+    }
+
+    /// Register `factory` with lowest priority, replacing the one with same `name` in place.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl DynTickerFactory + 'static,
+    ) -> &mut Self {
+        let name = name.into();
+        let factory = Box::new(factory);
+        match self.entries.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = factory,
+            None => self.entries.push((name, factory)),
+        }
+        self
+    }
+
+    /// Register `factory` with highest priority, removing the one with same `name`.
+    pub fn register_first(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl DynTickerFactory + 'static,
+    ) -> &mut Self {
+        let name = name.into();
+        self.unregister(&name);
+        self.entries.insert(0, (name, Box::new(factory)));
+        self
+    }
+
+    pub fn unregister(&mut self, name: &str) -> Option<Box<dyn DynTickerFactory>> {
+        let index = self.entries.iter().position(|(n, _)| n == name)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn DynTickerFactory> {
+        self.entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, f)| f.as_ref())
+    }
+
+    /// Registered names in order of priority.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(n, _)| n.as_str())
+    }
+
+    /// First available factory and its name.
+    pub fn select(&self) -> Option<(&str, &dyn DynTickerFactory)> {
+        self.entries
+            .iter()
+            .find(|(_, f)| f.check())
+            .map(|(n, f)| (n.as_str(), f.as_ref()))
+    }
+
+    /// Construct a ticker by first available factory.
+    pub fn create(&self, task: impl FnMut() + 'static) -> Result<BoxedTicker, TickerError> {
+        match self.select() {
+            Some((_, factory)) => factory.create(Box::new(task)),
+            None => Err(TickerError::NoBackendAvailable),
+        }
+    }
+
+    /// Construct a ticker by factory registered as `name`,
+    /// [TickerError::NotRegistered] if there is none.
+    pub fn create_named(
+        &self,
+        name: &str,
+        task: impl FnMut() + 'static,
+    ) -> Result<BoxedTicker, TickerError> {
+        match self.get(name) {
+            Some(factory) => factory.create(Box::new(task)),
+            None => Err(TickerError::NotRegistered(name.to_string())),
+        }
+    }
+
+    /// Queue task once by first available factory.
+    pub fn spawn(&self, task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        match self.select() {
+            Some((_, factory)) => factory.spawn(Box::new(task)),
+            None => Err(TickerError::NoBackendAvailable),
+        }
+    }
+}
+
+impl Default for TickerRegistry {
+    fn default() -> Self {
+        use SelectedTicker::*;
+        let preferred = SelectedTicker::preference(Runtime::current());
+        // Backends never selected on current runtime go last, keeping all names registered
+        let builtins = [MessageChannel, Immediate, Timeout, AnimationFrame];
+        let mut registry = Self::empty();
+        for selected in preferred.iter().chain(&builtins) {
+            match selected {
+                MessageChannel => registry.register("message_channel", MessageChannelTickerFactory),
+                Immediate => registry.register("immediate", ImmediateTickerFactory),
+                Timeout => registry.register("timeout", TimeoutTickerFactory),
+                AnimationFrame => registry.register("animation_frame", AnimationFrameTickerFactory),
+                _ => continue,
+            };
+        }
+        #[cfg(not(target_family = "wasm"))]
+        registry.register("native", super::NativeTickerFactory);
+        registry
+    }
+}
@@ -1,7 +1,8 @@
-use crate::ticker::MessageChannelTicker;
-use crate::{State, TickerFactory};
+use crate::bindings::__wasm_ticker_message_channel_scheduler;
+use crate::state::StateCell;
+use crate::ticker::{FunctionTicker, MessageChannelTicker};
+use crate::{NamedTicker, State, TickerError, TickerFactory};
 use std::rc::Rc;
-use wasm_bindgen::__rt::WasmRefCell;
 use wasm_bindgen::prelude::*;
 use web_sys::MessageChannel;
 
@@ -9,29 +10,42 @@ use web_sys::MessageChannel;
 #[derive(Clone, Copy)]
 pub struct MessageChannelTickerFactory;
 
+impl MessageChannelTickerFactory {
+    /// Construct [MessageChannelTicker] not keeping Node or Bun process alive,
+    /// see [MessageChannelTicker::unref].
+    pub fn new_unref(task: impl FnMut() + 'static) -> Result<MessageChannelTicker, TickerError> {
+        let ticker = Self::new(task)?;
+        ticker.unref();
+        Ok(ticker)
+    }
+}
+
 impl TickerFactory for MessageChannelTickerFactory {
     type Output = MessageChannelTicker;
 
-    fn new(mut task: impl FnMut() + 'static) -> Result<Self::Output, JsValue> {
+    fn new(mut task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
         let channel = MessageChannel::new()?;
-        let state = Rc::new(WasmRefCell::new(State::Stopped));
+        let state = Rc::new(StateCell::new());
         let port1 = channel.port1();
         let port2 = channel.port2();
 
         let state_ = state.clone();
         let port2_ = port2.clone();
         let cb = Closure::new(move || {
-            if let State::Started = *state_.borrow() {
+            if state_.borrow().eq(&State::Started) {
                 task();
-                if let Err(e) = port2_.post_message(&JsValue::null()) {
-                    *state_.borrow_mut() = State::Error(e)
+                // Task may have stopped the ticker
+                if state_.borrow().eq(&State::Started) {
+                    if let Err(e) = port2_.post_message(&JsValue::null()) {
+                        state_.set(State::Error(TickerError::ScheduleFailed(e)))
+                    }
                 }
             }
         });
 
         let state_ = state.clone();
         let cb_err = Closure::new(move |e: JsValue| {
-            *state_.borrow_mut() = State::Error(e);
+            state_.set(State::Error(TickerError::MessageError(e)));
         });
 
         let ticker = MessageChannelTicker {
@@ -45,4 +59,16 @@ impl TickerFactory for MessageChannelTickerFactory {
         port1.set_onmessageerror(Some(ticker.cb_err.as_ref().as_ref().unchecked_ref()));
         Ok(ticker)
     }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        let scheduler = match MessageChannelTicker::check() {
+            true => Some(__wasm_ticker_message_channel_scheduler()?),
+            false => None,
+        };
+        FunctionTicker::with_scheduler(scheduler, Self::new, function, this, args)
+    }
 }
@@ -0,0 +1,14 @@
+use crate::ticker::MockTicker;
+use crate::{TickerError, TickerFactory};
+
+/// Factory type for [MockTicker], driven by virtual clock in [mock](crate::mock).
+#[derive(Clone, Copy)]
+pub struct MockTickerFactory;
+
+impl TickerFactory for MockTickerFactory {
+    type Output = MockTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        Ok(MockTicker::new(Box::new(task)))
+    }
+}
@@ -1,17 +1,30 @@
+mod animation_frame;
 mod auto;
+mod backend;
+mod dynamic;
 mod message_channel;
+mod mock;
+#[cfg(not(target_family = "wasm"))]
+mod native;
 mod timers;
+mod wait_async;
 
 pub use auto::AutoTickerFactory;
+pub(crate) use backend::reschedule;
+pub use backend::BackendTickerFactory;
+pub use dynamic::{DynTickerFactory, TickerRegistry};
 pub use message_channel::MessageChannelTickerFactory;
+pub use mock::MockTickerFactory;
+#[cfg(not(target_family = "wasm"))]
+pub use native::NativeTickerFactory;
 
-/// Factory type for [AnimationFrameTicker](crate::ticker::AnimationFrameTicker).
-pub use timers::AnimationFrameTickerFactory;
+pub use animation_frame::AnimationFrameTickerFactory;
 
-/// Factory type for [TimeoutTicker](crate::ticker::TimeoutTicker).
-pub use timers::TimeoutTickerFactory;
+pub use timers::{
+    ImmediateTickerFactory, TimeoutTickerFactory, UnrefImmediateTickerFactory,
+    UnrefTimeoutTickerFactory,
+};
 
-/// Factory type for [ImmediateTicker](crate::ticker::ImmediateTicker).
-pub use timers::ImmediateTickerFactory;
+pub use wait_async::WaitAsyncTickerFactory;
 
 pub(crate) use auto::{SelectedTicker, SELECTED_TICKER};
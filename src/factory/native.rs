@@ -0,0 +1,14 @@
+use crate::ticker::NativeTicker;
+use crate::{TickerError, TickerFactory};
+
+/// Factory type for [NativeTicker], available on native targets.
+#[derive(Clone, Copy)]
+pub struct NativeTickerFactory;
+
+impl TickerFactory for NativeTickerFactory {
+    type Output = NativeTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        Ok(NativeTicker::new(Box::new(task)))
+    }
+}
@@ -1,68 +1,87 @@
-use crate::{State, TickerFactory};
-use std::rc::Rc;
-use wasm_bindgen::__rt::WasmRefCell;
-use wasm_bindgen::prelude::*;
-
-macro_rules! timer_ticker_factory_impl {
-    {
-        Ticker = $T:ty,
-        TickerFactory = $TFactory:ident,
-        Timer = $FTimer:path,
-    } => {
-    #[derive(Clone, Copy)]
-    pub struct $TFactory;
-
-    impl TickerFactory for $TFactory {
-        type Output = $T;
-
-        fn new(mut task: impl FnMut() + 'static) -> Result<Self::Output, JsValue> {
-            let state = Rc::new(WasmRefCell::new(State::Stopped));
-            let token = Rc::new(WasmRefCell::new(None));
-
-            let state_ = state.clone();
-            let token_ = token.clone();
-            let cb = Rc::new_cyclic(move |weak| {
-                let weak = weak.clone();
-                Closure::new(move || {
-                    if state_.borrow().eq(&State::Started) {
-                        task();
-                        if let Some(cb) = weak.upgrade() {
-                            match $FTimer(AsRef::<Closure<dyn FnMut()>>::as_ref(&cb).as_ref().unchecked_ref()) {
-                                Ok(token) => {
-                                    token_.borrow_mut().replace(token);
-                                },
-                                Err(e) => {
-                                    *state_.borrow_mut() = State::Error(e);
-                                }
-                            }
-                        }
-                    }
-                })
-            });
-            Ok(Self::Output {
-                token,
-                state,
-                cb,
-            })
-        }
+use super::BackendTickerFactory;
+use crate::backend::{SetImmediate, SetTimeout, Unref};
+use crate::ticker::{
+    FunctionTicker, ImmediateTicker, TimeoutTicker, UnrefImmediateTicker, UnrefTimeoutTicker,
+};
+use crate::{TickerError, TickerFactory};
+use wasm_bindgen::JsValue;
+
+/// Factory type for [ImmediateTicker].
+#[derive(Clone, Copy)]
+pub struct ImmediateTickerFactory;
+
+impl TickerFactory for ImmediateTickerFactory {
+    type Output = ImmediateTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        BackendTickerFactory::<SetImmediate>::new(task)
+    }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        BackendTickerFactory::<SetImmediate>::new_with_function(function, this, args)
     }
-    };
 }
 
-timer_ticker_factory_impl! {
-    Ticker = crate::ticker::ImmediateTicker,
-    TickerFactory = ImmediateTickerFactory,
-    Timer = crate::bindings::__wasm_ticker_binding_set_immediate,
+/// Factory type for [TimeoutTicker].
+#[derive(Clone, Copy)]
+pub struct TimeoutTickerFactory;
+
+impl TickerFactory for TimeoutTickerFactory {
+    type Output = TimeoutTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        BackendTickerFactory::<SetTimeout>::new(task)
+    }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        BackendTickerFactory::<SetTimeout>::new_with_function(function, this, args)
+    }
 }
 
-timer_ticker_factory_impl! {
-    Ticker = crate::ticker::TimeoutTicker,
-    TickerFactory = TimeoutTickerFactory,
-    Timer = crate::bindings::__wasm_ticker_binding_set_timeout,
+/// Factory type for [UnrefImmediateTicker].
+#[derive(Clone, Copy)]
+pub struct UnrefImmediateTickerFactory;
+
+impl TickerFactory for UnrefImmediateTickerFactory {
+    type Output = UnrefImmediateTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        BackendTickerFactory::<Unref<SetImmediate>>::new(task)
+    }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        BackendTickerFactory::<Unref<SetImmediate>>::new_with_function(function, this, args)
+    }
 }
 
-timer_ticker_factory_impl! {
-    Ticker = crate::ticker::AnimationFrameTicker,
-    TickerFactory = AnimationFrameTickerFactory,
-    Timer = crate::bindings::__wasm_ticker_binding_request_animation_frame,
+/// Factory type for [UnrefTimeoutTicker].
+#[derive(Clone, Copy)]
+pub struct UnrefTimeoutTickerFactory;
+
+impl TickerFactory for UnrefTimeoutTickerFactory {
+    type Output = UnrefTimeoutTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        BackendTickerFactory::<Unref<SetTimeout>>::new(task)
+    }
+
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<FunctionTicker<Self::Output>, TickerError> {
+        BackendTickerFactory::<Unref<SetTimeout>>::new_with_function(function, this, args)
+    }
 }
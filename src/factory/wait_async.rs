@@ -0,0 +1,72 @@
+use crate::bindings::{memory_i32_index, memory_i32_view};
+use crate::state::StateCell;
+use crate::ticker::{WaitAsyncTicker, Waiter, WAIT_ASYNC_UNAVAILABLE};
+use crate::{NamedTicker, TickerError, TickerFactory};
+use js_sys::{Int32Array, SharedArrayBuffer};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::AtomicI32;
+use std::sync::Arc;
+use wasm_bindgen::__rt::WasmRefCell;
+
+/// Factory type for [WaitAsyncTicker].
+///
+/// [TickerFactory::new] waits on a new [SharedArrayBuffer] slot,
+/// see [WaitAsyncTicker::array].
+#[derive(Clone, Copy)]
+pub struct WaitAsyncTickerFactory;
+
+impl WaitAsyncTickerFactory {
+    /// Construct [WaitAsyncTicker] waiting on `array[index]`,
+    /// `array` must be backed by a [SharedArrayBuffer].
+    pub fn new_with_slot(
+        array: Int32Array,
+        index: u32,
+        task: impl FnMut() + 'static,
+    ) -> Result<WaitAsyncTicker, TickerError> {
+        Self::new_impl(array, index, None, task)
+    }
+
+    /// Construct [WaitAsyncTicker] waiting on `atomic` in shared WebAssembly memory,
+    /// which other threads of a `+atomics` build notify by
+    /// [WaitAsyncTicker::notify_atomic].
+    pub fn new_with_atomic(
+        atomic: Arc<AtomicI32>,
+        task: impl FnMut() + 'static,
+    ) -> Result<WaitAsyncTicker, TickerError> {
+        let index = memory_i32_index(&atomic);
+        Self::new_impl(memory_i32_view(), index, Some(atomic), task)
+    }
+
+    fn new_impl(
+        array: Int32Array,
+        index: u32,
+        atomic: Option<Arc<AtomicI32>>,
+        task: impl FnMut() + 'static,
+    ) -> Result<WaitAsyncTicker, TickerError> {
+        if !WaitAsyncTicker::check() {
+            return Err(TickerError::Unavailable(WAIT_ASYNC_UNAVAILABLE));
+        }
+        Ok(WaitAsyncTicker {
+            waiter: Rc::new(Waiter {
+                array,
+                index,
+                state: StateCell::new(),
+                waiting: Cell::new(false),
+                task: WasmRefCell::new(Some(Box::new(task))),
+                _atomic: atomic,
+            }),
+        })
+    }
+}
+
+impl TickerFactory for WaitAsyncTickerFactory {
+    type Output = WaitAsyncTicker;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        if !WaitAsyncTicker::check() {
+            return Err(TickerError::Unavailable(WAIT_ASYNC_UNAVAILABLE));
+        }
+        Self::new_with_slot(Int32Array::new(&SharedArrayBuffer::new(4)), 0, task)
+    }
+}
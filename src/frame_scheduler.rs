@@ -0,0 +1,175 @@
+use crate::factory::{AnimationFrameTickerFactory, AutoTickerFactory, TimeoutTickerFactory};
+use crate::ticker::{AnimationFrameTicker, AutoTicker, TimeoutTicker};
+use crate::{NamedTicker, State, Ticker, TickerError, TickerFactory};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+use wasm_bindgen::__rt::WasmRefCell;
+
+/// Phase of a frame of [FrameScheduler], all reads run before all writes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    /// Measure layout, e.g. `getBoundingClientRect`.
+    Read,
+    /// Mutate DOM or draw.
+    Write,
+}
+
+struct Queue<K> {
+    /// Keys in order of first request, may contain cancelled ones.
+    order: Vec<K>,
+    tasks: HashMap<K, Box<dyn FnOnce()>>,
+}
+
+impl<K: Clone + Eq + Hash> Queue<K> {
+    fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            tasks: HashMap::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    fn insert(&mut self, key: K, task: Box<dyn FnOnce()>) {
+        if self.tasks.insert(key.clone(), task).is_none() {
+            self.order.push(key);
+        }
+    }
+
+    fn run(mut self) {
+        for key in self.order {
+            if let Some(task) = self.tasks.remove(&key) {
+                task();
+            }
+        }
+    }
+}
+
+struct Shared<K> {
+    reads: WasmRefCell<Queue<K>>,
+    writes: WasmRefCell<Queue<K>>,
+    /// Clone of ticker to stop itself when idle.
+    ticker: WasmRefCell<Option<AutoTicker>>,
+}
+
+impl<K: Clone + Eq + Hash> Shared<K> {
+    fn queue(&self, phase: Phase) -> &WasmRefCell<Queue<K>> {
+        match phase {
+            Phase::Read => &self.reads,
+            Phase::Write => &self.writes,
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.reads.borrow().is_empty() && self.writes.borrow().is_empty()
+    }
+
+    fn frame(&self) {
+        let reads = std::mem::replace(&mut *self.reads.borrow_mut(), Queue::new());
+        reads.run();
+        // Including writes requested by reads of this frame
+        let writes = std::mem::replace(&mut *self.writes.borrow_mut(), Queue::new());
+        writes.run();
+        if self.is_idle() {
+            if let Some(ticker) = &*self.ticker.borrow() {
+                ticker.stop();
+            }
+        }
+    }
+}
+
+/// Coalesce keyed requests into one animation frame.
+///
+/// Requests of the same key and [Phase] before the frame are merged, keeping the latest task
+/// at the position of the first one. On each frame, all [Phase::Read] tasks run before all
+/// [Phase::Write] tasks to avoid layout thrashing. Reads requested by writes run on next frame.
+///
+/// Driven by [AnimationFrameTicker], or [TimeoutTicker] if not available, started on request
+/// and stopped once no work is pending.
+///
+/// ```ignore
+/// let scheduler = FrameScheduler::new()?;
+/// scheduler.write("chart", move || chart.redraw())?;
+/// scheduler.write("chart", move || chart.redraw())?; // Merged
+/// ```
+pub struct FrameScheduler<K> {
+    ticker: AutoTicker,
+    shared: Rc<Shared<K>>,
+}
+
+impl<K: Clone + Eq + Hash + 'static> FrameScheduler<K> {
+    pub fn new() -> Result<Self, TickerError> {
+        let shared = Rc::new(Shared {
+            reads: WasmRefCell::new(Queue::new()),
+            writes: WasmRefCell::new(Queue::new()),
+            ticker: WasmRefCell::new(None),
+        });
+
+        let shared_ = shared.clone();
+        let task = move || shared_.frame();
+        let ticker = if AnimationFrameTicker::check() {
+            AutoTicker::AnimationFrame(AnimationFrameTickerFactory::new(task)?)
+        } else if TimeoutTicker::check() {
+            AutoTicker::Timeout(TimeoutTickerFactory::new(task)?)
+        } else {
+            AutoTickerFactory::new(task)?
+        };
+        shared.ticker.borrow_mut().replace(ticker.clone());
+        Ok(Self { ticker, shared })
+    }
+
+    /// Underlying [AnimationFrameTicker], [TimeoutTicker] or [AutoTicker].
+    pub fn ticker(&self) -> &AutoTicker {
+        &self.ticker
+    }
+
+    /// Run `task` in `phase` of next frame, replacing pending task of the same key and phase.
+    pub fn request(
+        &self,
+        phase: Phase,
+        key: K,
+        task: impl FnOnce() + 'static,
+    ) -> Result<(), TickerError> {
+        self.shared
+            .queue(phase)
+            .borrow_mut()
+            .insert(key, Box::new(task));
+        if self.ticker.state() != State::Started {
+            self.ticker.start()?;
+        }
+        Ok(())
+    }
+
+    /// [FrameScheduler::request] in [Phase::Read].
+    pub fn read(&self, key: K, task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        self.request(Phase::Read, key, task)
+    }
+
+    /// [FrameScheduler::request] in [Phase::Write].
+    pub fn write(&self, key: K, task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        self.request(Phase::Write, key, task)
+    }
+
+    /// Drop pending tasks of `key` in both phases, returning whether any was pending.
+    pub fn cancel(&self, key: &K) -> bool {
+        let read = self.shared.reads.borrow_mut().tasks.remove(key).is_some();
+        let write = self.shared.writes.borrow_mut().tasks.remove(key).is_some();
+        read || write
+    }
+
+    /// Whether no task is pending.
+    pub fn is_idle(&self) -> bool {
+        self.shared.is_idle()
+    }
+}
+
+impl<K> Drop for FrameScheduler<K> {
+    fn drop(&mut self) {
+        self.ticker.stop();
+        // Break reference cycle of the ticker stopping itself
+        self.shared.ticker.borrow_mut().take();
+    }
+}
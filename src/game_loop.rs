@@ -0,0 +1,171 @@
+use crate::factory::{AnimationFrameTickerFactory, AutoTickerFactory, TimeoutTickerFactory};
+use crate::ticker::{AnimationFrameTicker, AutoTicker, TimeoutTicker};
+use crate::time::Instant;
+use crate::{NamedTicker, State, Ticker, TickerError, TickerFactory};
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+use web_sys::AbortSignal;
+
+const DEFAULT_MAX_FRAME_TIME: Duration = Duration::from_millis(250);
+
+struct Clock {
+    timestep: Duration,
+    max_frame_time: Duration,
+    last: Option<Instant>,
+    accumulator: Duration,
+}
+
+impl Clock {
+    /// Advance to `now`, returning count of update steps and interpolation alpha.
+    fn advance(&mut self, now: Instant) -> (u32, f64) {
+        let frame_time = match self.last.replace(now) {
+            Some(last) => (now - last).min(self.max_frame_time),
+            None => Duration::ZERO,
+        };
+        self.accumulator += frame_time;
+        let mut steps = 0;
+        while self.accumulator >= self.timestep {
+            self.accumulator -= self.timestep;
+            steps += 1;
+        }
+        let alpha = self.accumulator.as_secs_f64() / self.timestep.as_secs_f64();
+        (steps, alpha)
+    }
+}
+
+/// Fixed timestep game loop.
+///
+/// On each frame, calls `update(timestep)` zero or more times to consume
+/// elapsed time, then calls `render(alpha)` once, where `alpha` in `[0, 1)` is
+/// the fraction of a timestep left in accumulator, for interpolating between states.
+///
+/// Elapsed time per frame is clamped by [GameLoop::max_frame_time] (250ms by default),
+/// so a long stall won't trigger endless updates.
+///
+/// Driven by [AnimationFrameTicker], or [TimeoutTicker] if not available,
+/// e.g. in NodeJs for headless tests, or [AutoTicker] on native targets.
+pub struct GameLoop {
+    ticker: AutoTicker,
+    clock: Rc<WasmRefCell<Clock>>,
+}
+
+impl GameLoop {
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    pub fn new(
+        timestep: Duration,
+        mut update: impl FnMut(Duration) + 'static,
+        mut render: impl FnMut(f64) + 'static,
+    ) -> Result<Self, TickerError> {
+        assert!(timestep > Duration::ZERO, "`timestep` must be non-zero.");
+        let clock = Rc::new(WasmRefCell::new(Clock {
+            timestep,
+            max_frame_time: DEFAULT_MAX_FRAME_TIME,
+            last: None,
+            accumulator: Duration::ZERO,
+        }));
+
+        let clock_ = clock.clone();
+        let task = move || {
+            let (steps, alpha, timestep) = {
+                let mut clock = clock_.borrow_mut();
+                let (steps, alpha) = clock.advance(Instant::now());
+                (steps, alpha, clock.timestep)
+            };
+            for _ in 0..steps {
+                update(timestep);
+            }
+            render(alpha);
+        };
+
+        let ticker = if AnimationFrameTicker::check() {
+            AutoTicker::AnimationFrame(AnimationFrameTickerFactory::new(task)?)
+        } else if TimeoutTicker::check() {
+            AutoTicker::Timeout(TimeoutTickerFactory::new(task)?)
+        } else {
+            AutoTickerFactory::new(task)?
+        };
+        Ok(Self { ticker, clock })
+    }
+
+    /// Underlying [AnimationFrameTicker], [TimeoutTicker] or [AutoTicker].
+    pub fn ticker(&self) -> &AutoTicker {
+        &self.ticker
+    }
+
+    pub fn timestep(&self) -> Duration {
+        self.clock.borrow().timestep
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    pub fn set_timestep(&self, timestep: Duration) {
+        assert!(timestep > Duration::ZERO, "`timestep` must be non-zero.");
+        self.clock.borrow_mut().timestep = timestep;
+    }
+
+    pub fn max_frame_time(&self) -> Duration {
+        self.clock.borrow().max_frame_time
+    }
+
+    pub fn set_max_frame_time(&self, max_frame_time: Duration) {
+        self.clock.borrow_mut().max_frame_time = max_frame_time;
+    }
+
+    /// Time between stop and start is not accumulated.
+    fn reset_clock(&self) {
+        let mut clock = self.clock.borrow_mut();
+        clock.last = None;
+        clock.accumulator = Duration::ZERO;
+    }
+}
+
+impl Ticker for GameLoop {
+    fn state(&self) -> State {
+        self.ticker.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        if self.state() != State::Started {
+            self.reset_clock();
+        }
+        self.ticker.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        if self.state() != State::Started {
+            self.reset_clock();
+        }
+        self.ticker.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.ticker.stop()
+    }
+
+    fn reset(&self) {
+        self.ticker.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.ticker.on_state_change(observer)
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        self.ticker.abort_signal()
+    }
+
+    /// Queue task once on next animation frame, falling back like [GameLoop::new].
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        if AnimationFrameTicker::check() {
+            AnimationFrameTicker::spawn(task)
+        } else if TimeoutTicker::check() {
+            TimeoutTicker::spawn(task)
+        } else {
+            AutoTicker::spawn(task)
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::abort::spawn_with_signal;
+use crate::bindings::__wasm_ticker_rethrow;
+use crate::factory::{
+    AnimationFrameTickerFactory, AutoTickerFactory, ImmediateTickerFactory,
+    MessageChannelTickerFactory, TickerRegistry, TimeoutTickerFactory, SELECTED_TICKER,
+};
+use crate::ticker::{AutoTicker, BoxedTicker, FunctionTicker};
+use crate::{State, Ticker, TickerError, TickerFactory};
+use wasm_bindgen::prelude::*;
+use web_sys::AbortSignal;
+
+/// Backend selected by [AutoTickerFactory], also the default.
+const AUTO: &str = "auto";
+
+/// [State] exposed to JavaScript, errors are read by `WasmTicker.error`.
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WasmTickerState {
+    Started = "started",
+    Stopped = "stopped",
+    Error = "error",
+}
+
+/// Ticker class exported to JavaScript, requires `js-api` feature.
+///
+/// ```ts
+/// const ticker = new WasmTicker("auto", () => render());
+/// ticker.start();
+/// ticker.state; // "started"
+/// ```
+///
+/// Backends are `"auto"` for [AutoTicker], or names of the default
+/// [TickerRegistry]: `"message_channel"`, `"immediate"`, `"timeout"` and `"animation_frame"`.
+/// An exception thrown by the callback stops the ticker with state `"error"`.
+#[wasm_bindgen]
+pub struct WasmTicker {
+    ticker: BoxedTicker,
+    backend: String,
+}
+
+#[wasm_bindgen]
+impl WasmTicker {
+    /// Construct a ticker calling `callback` on each tick, by `backend` or `"auto"` if omitted.
+    #[wasm_bindgen(constructor)]
+    pub fn new(backend: Option<String>, callback: js_sys::Function) -> Result<WasmTicker, JsValue> {
+        let backend = backend.unwrap_or_else(|| AUTO.to_string());
+        let (this, args) = (JsValue::UNDEFINED, js_sys::Array::new());
+        // Built-in backends loop in JavaScript, calling `callback` without entering WebAssembly
+        let ticker = match backend.as_str() {
+            AUTO => BoxedTicker::new(AutoTickerFactory::new_with_function(callback, this, args)?),
+            "message_channel" => BoxedTicker::new(MessageChannelTickerFactory::new_with_function(
+                callback, this, args,
+            )?),
+            "immediate" => BoxedTicker::new(ImmediateTickerFactory::new_with_function(
+                callback, this, args,
+            )?),
+            "timeout" => BoxedTicker::new(TimeoutTickerFactory::new_with_function(
+                callback, this, args,
+            )?),
+            "animation_frame" => BoxedTicker::new(AnimationFrameTickerFactory::new_with_function(
+                callback, this, args,
+            )?),
+            _ => {
+                let registry = TickerRegistry::default();
+                if registry.get(&backend).is_none() {
+                    return Err(TickerError::NotRegistered(backend).into());
+                }
+                BoxedTicker::new(FunctionTicker::new(
+                    |task| registry.create_named(&backend, task),
+                    callback,
+                    this,
+                    args,
+                )?)
+            }
+        };
+        let backend = match backend.as_str() {
+            AUTO => SELECTED_TICKER.name().unwrap_or(AUTO).to_string(),
+            _ => backend,
+        };
+        Ok(Self { ticker, backend })
+    }
+
+    /// Name of the backend, the selected one for `"auto"`.
+    #[wasm_bindgen(getter)]
+    pub fn backend(&self) -> String {
+        self.backend.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn state(&self) -> WasmTickerState {
+        match self.ticker.state() {
+            State::Started => WasmTickerState::Started,
+            State::Stopped => WasmTickerState::Stopped,
+            State::Error(_) => WasmTickerState::Error,
+        }
+    }
+
+    /// Error of state `"error"`, `undefined` in other states.
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> JsValue {
+        match self.ticker.state() {
+            State::Error(e) => e.into(),
+            _ => JsValue::UNDEFINED,
+        }
+    }
+
+    pub fn start(&self) -> Result<(), JsValue> {
+        Ok(self.ticker.start()?)
+    }
+
+    #[wasm_bindgen(js_name = startImmediate)]
+    pub fn start_immediate(&self) -> Result<(), JsValue> {
+        Ok(self.ticker.start_immediate()?)
+    }
+
+    pub fn stop(&self) {
+        self.ticker.stop()
+    }
+
+    /// Clear state `"error"` to `"stopped"`.
+    pub fn reset(&self) {
+        self.ticker.reset()
+    }
+
+    /// Stop once `signal` aborts, at once if already aborted.
+    #[wasm_bindgen(js_name = stopOnAbort)]
+    pub fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), JsValue> {
+        Ok(self.ticker.stop_on_abort(signal)?)
+    }
+
+    /// `AbortSignal` aborting on next stop, with the error as reason on state `"error"`.
+    #[wasm_bindgen(js_name = abortSignal)]
+    pub fn abort_signal(&self) -> Result<AbortSignal, JsValue> {
+        Ok(self.ticker.abort_signal()?)
+    }
+
+    /// Queue `callback` once, by `backend` or `"auto"` if omitted,
+    /// dropping it if `signal` aborts before it runs.
+    ///
+    /// An exception thrown by `callback` is rethrown from a microtask, reported as uncaught.
+    pub fn spawn(
+        callback: js_sys::Function,
+        backend: Option<String>,
+        signal: Option<AbortSignal>,
+    ) -> Result<(), JsValue> {
+        let task = move || {
+            if let Err(e) = callback.call0(&JsValue::UNDEFINED) {
+                __wasm_ticker_rethrow(&e);
+            }
+        };
+        let backend = backend.unwrap_or_else(|| AUTO.to_string());
+        let spawn = |task: Box<dyn FnOnce()>| {
+            if backend == AUTO {
+                return AutoTicker::spawn(task);
+            }
+            match TickerRegistry::default().get(&backend) {
+                Some(factory) => factory.spawn(task),
+                None => Err(TickerError::NotRegistered(backend.clone())),
+            }
+        };
+        match signal {
+            Some(signal) => Ok(spawn_with_signal(spawn, task, &signal, |_| ())?),
+            None => Ok(spawn(Box::new(task))?),
+        }
+    }
+}
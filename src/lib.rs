@@ -10,30 +10,82 @@
 //! |         Ticker         |           API           | Platform |      Interval<br/>Browser / Node      |
 //! |:----------------------:|:-----------------------:|:--------:|:-------------------------------------:|
 //! | [MessageChannelTicker] |   [Channel Messaging]   |    *     |             \>4µs / \<1µs             |
-//! |    [ImmediateTicker]   |     [setImmediate]      |   Node   |                 \~1µs                 |
+//! |    [ImmediateTicker]   |     [setImmediate]      | Node, Bun|                 \~1µs                 |
 //! |    [TimeoutTicker]     |      [setTimeout]       |    *     | [\~4ms][setTimeout interval] / \~14ms |
 //! | [AnimationFrameTicker] | [requestAnimationFrame] | Browser  |          According to device          |
+//! |   [WaitAsyncTicker]    |   [Atomics.waitAsync]   |    *     |               On notify               |
+//! |    [BackendTicker]     | Custom [ScheduleBackend]|    *     |         According to backend          |
 //! |      [AutoTicker]      |      One of above       |    *     |                  N/A                  |
 //!
+//! Browser, Node, Deno, Bun and workers are detected by [Runtime],
+//! which decides the preference of [AutoTicker].
+//!
 //! [MessageChannelTicker]: ticker::MessageChannelTicker
 //! [ImmediateTicker]: ticker::ImmediateTicker
 //! [TimeoutTicker]: ticker::TimeoutTicker
 //! [AnimationFrameTicker]: ticker::AnimationFrameTicker
+//! [WaitAsyncTicker]: ticker::WaitAsyncTicker
 //! [AutoTicker]: ticker::AutoTicker
+//! [BackendTicker]: ticker::BackendTicker
 //!
 //! [Channel Messaging]: https://developer.mozilla.org/docs/Web/API/Channel_Messaging_API
 //! [setTimeout]: https://developer.mozilla.org/docs/Web/API/setTimeout
 //! [requestAnimationFrame]: https://developer.mozilla.org/docs/Web/API/Window/requestAnimationFrame
 //! [setImmediate]: https://nodejs.org/en-us/learn/asynchronous-work/understanding-setimmediate
+//! [Atomics.waitAsync]: https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync
 //!
 //! [setTimeout interval]: https://developer.mozilla.org/docs/Web/API/setTimeout#reasons_for_delays_longer_than_specified
 
+mod abort;
+/// Built-in [ScheduleBackend] implementations
+pub mod backend;
 mod bindings;
+mod bounded;
+/// Process large iterators across ticks without blocking
+pub mod chunked;
+mod completion;
+/// Async bodies resumed on ticks, suspending by `yield_tick`
+pub mod coroutine;
+/// Debounced and throttled callbacks using `setTimeout` or animation frames
+pub mod debounce;
+mod error;
+mod frame_scheduler;
+mod game_loop;
+/// Virtual clock for deterministic tests of ticker-driven logic
+pub mod mock;
+/// Event loop of [NativeTicker](ticker::NativeTicker) on native targets
+#[cfg(not(target_family = "wasm"))]
+pub mod native;
+mod runtime;
+mod state;
+/// Dependency graph of tasks executed across ticks
+pub mod task_graph;
 
 /// Factory types implement [TickerFactory]
 pub mod factory;
 /// Types implement [Ticker]
 pub mod ticker;
+/// Sleep, timeout and interval futures using `setTimeout`
+pub mod time;
+mod yield_now;
+
+/// Async [Stream](futures_core::Stream) of ticks, requires `futures-core` feature.
+#[cfg(feature = "futures-core")]
+pub mod stream;
+
+/// `WasmTicker` class exported to JavaScript, requires `js-api` feature.
+#[cfg(feature = "js-api")]
+pub mod js;
+/// Thread-safe [Ticker] handle, requires `atomics` feature.
+#[cfg(feature = "atomics")]
+pub mod sync;
+
+pub use bounded::{Bounded, Bounds, Completion, Finish};
+pub use error::TickerError;
+pub use frame_scheduler::{FrameScheduler, Phase};
+pub use game_loop::GameLoop;
+pub use runtime::Runtime;
+pub use yield_now::{yield_if_needed, yield_now, YieldNow};
 
 use wasm_bindgen::JsValue;
 
@@ -42,12 +94,27 @@ use wasm_bindgen::JsValue;
 pub enum State {
     Started,
     Stopped,
-    Error(JsValue),
+    Error(TickerError),
 }
 
 pub trait TickerFactory {
     type Output: Ticker + Clone + PartialEq + Eq;
-    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, JsValue>;
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError>;
+    /// Construct a ticker applying JavaScript `function` to `this` and `args` on each tick.
+    ///
+    /// Built-in factories re-arm in a JavaScript loop calling `function` directly,
+    /// so ticks don't enter WebAssembly. By default `function` is wrapped in a task.
+    /// An exception thrown by `function` stops the ticker with [State::Error].
+    fn new_with_function(
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<ticker::FunctionTicker<Self::Output>, TickerError>
+    where
+        Self::Output: 'static,
+    {
+        ticker::FunctionTicker::new(|task| Self::new(task), function, this, args)
+    }
 }
 
 /// A [Ticker] queues callback as a [Task] to JavaScript event loop.
@@ -62,17 +129,30 @@ pub trait Ticker {
     fn state(&self) -> State;
 
     /// Start queuing on next tick, this doesn't block current context.
-    fn start(&self) -> Result<(), JsValue>;
+    fn start(&self) -> Result<(), TickerError>;
 
     /// Call callback once and start queuing.
-    fn start_immediate(&self) -> Result<(), JsValue>;
+    fn start_immediate(&self) -> Result<(), TickerError>;
 
     /// Stop executing, implementations may not force cancel queued task,
     /// or just set state to [State::Stopped].
     fn stop(&self);
 
+    /// Clear [State::Error] to [State::Stopped], so the ticker can be started again
+    /// keeping its identity. Does nothing in other states.
+    ///
+    /// Does nothing by default, for tickers never entering [State::Error].
+    fn reset(&self) {}
+
+    /// Call `observer` with new state on each change of [Ticker::state].
+    ///
+    /// Drops `observer` by default, for tickers not tracking changes.
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        drop(observer);
+    }
+
     /// Simply queue task once.
-    fn spawn(task: impl FnOnce() + 'static) -> Result<(), JsValue>
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError>
     where
         Self: Sized;
 
@@ -83,11 +163,11 @@ pub trait Ticker {
     /// [withResolvers]: https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
     fn spawn_promise(
         task: impl FnOnce() -> Result<JsValue, JsValue> + 'static,
-    ) -> Result<js_sys::Promise, JsValue>
+    ) -> Result<js_sys::Promise, TickerError>
     where
         Self: Sized,
     {
-        let resolvers = bindings::__wasm_ticker_binding_promise_resolvers()?;
+        let resolvers = bindings::promise_resolvers()?;
         let promise = resolvers.__wasm_ticker_binding_promise();
         let resolve = resolvers.__wasm_ticker_binding_resolve();
         let reject = resolvers.__wasm_ticker_binding_reject();
@@ -100,6 +180,77 @@ pub trait Ticker {
         })?;
         Ok(promise)
     }
+
+    /// Stop once `signal` aborts, at once if already aborted.
+    ///
+    /// Built-in tickers are only weakly referenced by the `abort` listener,
+    /// which they remove once dropped.
+    /// The default implementation only stops the current or next run: it keeps a clone
+    /// until the signal aborts or the ticker stops, as reported by [Ticker::on_state_change].
+    fn stop_on_abort(&self, signal: &web_sys::AbortSignal) -> Result<(), TickerError>
+    where
+        Self: Sized + Clone + 'static,
+    {
+        abort::stop_on_abort_once(self, signal)
+    }
+
+    /// Construct an [AbortSignal](web_sys::AbortSignal) aborting on next stop,
+    /// with the [TickerError] as reason if stopped by [State::Error].
+    ///
+    /// Built-in tickers remove the observer once aborted,
+    /// the default implementation leaves a no-op observer by [Ticker::on_state_change].
+    fn abort_signal(&self) -> Result<web_sys::AbortSignal, TickerError> {
+        abort::observe_abort_signal(self)
+    }
+
+    /// Like [Ticker::spawn], but drops the task if `signal` aborts before it runs.
+    ///
+    /// Fails with [TickerError::Cancelled] if already aborted.
+    fn spawn_with_signal(
+        task: impl FnOnce() + 'static,
+        signal: &web_sys::AbortSignal,
+    ) -> Result<(), TickerError>
+    where
+        Self: Sized,
+    {
+        abort::spawn_with_signal(|task| Self::spawn(task), task, signal, |_| ())
+    }
+
+    /// Like [Ticker::spawn_promise], but drops the task and rejects the promise
+    /// with abort reason if `signal` aborts before it runs.
+    ///
+    /// Returns a rejected promise without queuing if already aborted.
+    fn spawn_promise_with_signal(
+        task: impl FnOnce() -> Result<JsValue, JsValue> + 'static,
+        signal: &web_sys::AbortSignal,
+    ) -> Result<js_sys::Promise, TickerError>
+    where
+        Self: Sized,
+    {
+        if signal.aborted() {
+            return Ok(js_sys::Promise::reject(&signal.reason()));
+        }
+        let resolvers = bindings::promise_resolvers()?;
+        let promise = resolvers.__wasm_ticker_binding_promise();
+        let resolve = resolvers.__wasm_ticker_binding_resolve();
+        let reject = resolvers.__wasm_ticker_binding_reject();
+        let reject_ = reject.clone();
+        abort::spawn_with_signal(
+            |task| Self::spawn(task),
+            move || {
+                match task() {
+                    Ok(r) => resolve.call1(&JsValue::null(), &r),
+                    Err(e) => reject_.call1(&JsValue::null(), &e),
+                }
+                .unwrap();
+            },
+            signal,
+            move |reason| {
+                reject.call1(&JsValue::null(), &reason).unwrap();
+            },
+        )?;
+        Ok(promise)
+    }
 }
 
 /// [Ticker] with specialized JavaScript API.
@@ -112,10 +263,68 @@ pub trait NamedTicker: Ticker {
 pub trait TimerTicker: NamedTicker {
     /// Returned token type of JavaScript timer API like `setTimeout`.
     ///
-    /// In browsers, this usually is [Number](js_sys::Number),
-    /// but in Node it could be a [Object](js_sys::Object).
+    /// In browsers and Deno, this usually is [Number](js_sys::Number),
+    /// but in Node and Bun it could be a `Timeout` or `Immediate` [Object](js_sys::Object).
+    /// Tokens are kept opaque and passed back unchanged to clearing APIs.
     type Token: AsRef<JsValue> + Clone;
 
     /// Get clone of latest [Self::Token] if exists.
     fn token(&self) -> Option<Self::Token>;
 }
+
+/// Host scheduling primitive queuing a callback once, like `setTimeout`.
+///
+/// Implement this for a custom primitive, e.g. provided by an embedder,
+/// to get a full [Ticker] and [TickerFactory] pair:
+/// [BackendTicker](ticker::BackendTicker) and [BackendTickerFactory](factory::BackendTickerFactory).
+///
+/// ```ignore
+/// #[wasm_bindgen]
+/// extern "C" {
+///     #[wasm_bindgen(catch, js_namespace = host, js_name = schedule)]
+///     fn host_schedule(cb: &js_sys::Function) -> Result<JsValue, JsValue>;
+///     #[wasm_bindgen(js_namespace = host, js_name = cancel)]
+///     fn host_cancel(token: JsValue);
+/// }
+///
+/// struct HostBackend;
+///
+/// impl ScheduleBackend for HostBackend {
+///     type Token = JsValue;
+///
+///     fn available() -> bool {
+///         js_sys::Reflect::has(&js_sys::global(), &"host".into()).unwrap_or(false)
+///     }
+///
+///     fn schedule(cb: &js_sys::Function) -> Result<JsValue, JsValue> {
+///         host_schedule(cb)
+///     }
+///
+///     fn cancel(token: JsValue) {
+///         host_cancel(token)
+///     }
+/// }
+///
+/// let ticker = BackendTickerFactory::<HostBackend>::new(|| ())?;
+/// ```
+pub trait ScheduleBackend: 'static {
+    /// Returned token of [Self::schedule], passed to [Self::cancel].
+    type Token: AsRef<JsValue> + Clone;
+
+    /// Check if the primitive is available on current JavaScript Runtime.
+    fn available() -> bool;
+
+    /// Queue `cb` to be called once.
+    fn schedule(cb: &js_sys::Function) -> Result<Self::Token, JsValue>;
+
+    /// Cancel queued callback, may be called after it was called.
+    fn cancel(token: Self::Token);
+
+    /// JavaScript functions equivalent to [Self::schedule] and [Self::cancel],
+    /// letting [TickerFactory::new_with_function] re-arm without entering WebAssembly.
+    ///
+    /// [None] by default, such tickers call the function from their Rust task.
+    fn js_functions() -> Option<(js_sys::Function, js_sys::Function)> {
+        None
+    }
+}
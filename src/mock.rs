@@ -0,0 +1,194 @@
+//! Drives [MockTicker](crate::ticker::MockTicker),
+//! constructed by [MockTickerFactory](crate::factory::MockTickerFactory).
+//! Nothing runs until [advance] or [advance_time] is called.
+//! Each round of the virtual event loop runs tasks queued before it,
+//! started tickers queue again after each tick, just like a real event loop.
+//!
+//! The clock is thread-local, call [reset] at the beginning of each test
+//! sharing a thread.
+//!
+//! ```ignore
+//! mock::reset();
+//! let ticker = MockTickerFactory::new(move || n_.set(n_.get() + 1))?;
+//! ticker.start()?;
+//! mock::advance(3);
+//! assert_eq!(n.get(), 3);
+//! ```
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Weak;
+use std::time::Duration;
+
+use crate::ticker::MockInner;
+
+/// Default virtual time between rounds.
+const DEFAULT_PERIOD: Duration = Duration::from_millis(1);
+
+/// Where an executed task came from.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MockSource {
+    /// Tick of [MockTicker](crate::ticker::MockTicker) with [id](crate::ticker::MockTicker::id).
+    Ticker(u64),
+    /// Task queued by [Ticker::spawn](crate::Ticker::spawn).
+    Spawn,
+}
+
+/// A task executed by the virtual clock, see [schedule].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MockEvent {
+    /// Round of virtual event loop, `0` if called by
+    /// [Ticker::start_immediate](crate::Ticker::start_immediate) before first round.
+    pub round: u64,
+    /// Virtual time since last [reset].
+    pub time: Duration,
+    pub source: MockSource,
+}
+
+pub(crate) enum Queued {
+    Tick(Weak<MockInner>),
+    Spawn(Box<dyn FnOnce()>),
+}
+
+pub(crate) struct Clock {
+    /// Incremented by [reset], ticks queued in earlier clocks are dropped.
+    generation: u64,
+    now: Duration,
+    round: u64,
+    period: Duration,
+    next_id: u64,
+    queue: VecDeque<Queued>,
+    events: Vec<MockEvent>,
+}
+
+impl Clock {
+    fn new(generation: u64) -> Self {
+        Self {
+            generation,
+            now: Duration::ZERO,
+            round: 0,
+            period: DEFAULT_PERIOD,
+            next_id: 0,
+            queue: VecDeque::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::new(0));
+}
+
+pub(crate) fn next_id() -> u64 {
+    CLOCK.with(|clock| {
+        let clock = &mut *clock.borrow_mut();
+        clock.next_id += 1;
+        clock.next_id
+    })
+}
+
+pub(crate) fn generation() -> u64 {
+    CLOCK.with(|clock| clock.borrow().generation)
+}
+
+pub(crate) fn enqueue(queued: Queued) {
+    CLOCK.with(|clock| clock.borrow_mut().queue.push_back(queued));
+}
+
+pub(crate) fn record(source: MockSource) {
+    CLOCK.with(|clock| {
+        let clock = &mut *clock.borrow_mut();
+        clock.events.push(MockEvent {
+            round: clock.round,
+            time: clock.now,
+            source,
+        });
+    });
+}
+
+/// Run one round, tasks queued meanwhile run in next round.
+fn run_round() {
+    let queue = CLOCK.with(|clock| {
+        let clock = &mut *clock.borrow_mut();
+        clock.now += clock.period;
+        clock.round += 1;
+        std::mem::take(&mut clock.queue)
+    });
+    for queued in queue {
+        match queued {
+            Queued::Tick(inner) => {
+                if let Some(inner) = inner.upgrade() {
+                    inner.tick();
+                }
+            }
+            Queued::Spawn(task) => {
+                record(MockSource::Spawn);
+                task();
+            }
+        }
+    }
+}
+
+/// Run `rounds` rounds of virtual event loop,
+/// each started ticker ticks once per round.
+pub fn advance(rounds: u64) {
+    for _ in 0..rounds {
+        run_round();
+    }
+}
+
+/// Advance virtual time by `duration`, running a round every [period].
+pub fn advance_time(duration: Duration) {
+    let target = now() + duration;
+    while now() + period() <= target {
+        run_round();
+    }
+    CLOCK.with(|clock| clock.borrow_mut().now = target);
+}
+
+/// Virtual time since last [reset].
+pub fn now() -> Duration {
+    CLOCK.with(|clock| clock.borrow().now)
+}
+
+/// Rounds run since last [reset].
+pub fn rounds() -> u64 {
+    CLOCK.with(|clock| clock.borrow().round)
+}
+
+/// Virtual time between rounds, `1ms` by default.
+pub fn period() -> Duration {
+    CLOCK.with(|clock| clock.borrow().period)
+}
+
+/// Set virtual time between rounds.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn set_period(period: Duration) {
+    assert!(period > Duration::ZERO, "`period` must be non-zero.");
+    CLOCK.with(|clock| clock.borrow_mut().period = period);
+}
+
+/// Tasks executed since last [reset], in order.
+pub fn schedule() -> Vec<MockEvent> {
+    CLOCK.with(|clock| clock.borrow().events.clone())
+}
+
+/// Whether any task is queued for next round.
+pub fn is_idle() -> bool {
+    CLOCK.with(|clock| clock.borrow().queue.is_empty())
+}
+
+/// Drop queued tasks and recorded schedule, and rewind virtual time.
+///
+/// Existing tickers keep their state, but won't tick until started again.
+pub fn reset() {
+    let clock = CLOCK.with(|clock| {
+        let clock = &mut *clock.borrow_mut();
+        let generation = clock.generation + 1;
+        std::mem::replace(clock, Clock::new(generation))
+    });
+    // Dropped outside of borrow, queued tasks may own tickers
+    drop(clock);
+}
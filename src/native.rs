@@ -0,0 +1,65 @@
+//! Single-threaded event loop driving [NativeTicker](crate::ticker::NativeTicker)
+//! on native targets, where [AutoTicker](crate::ticker::AutoTicker) selects it.
+//!
+//! Like JavaScript event loop, tasks are queued and run in order,
+//! but only while current thread calls [run], [run_for] or [run_once].
+//! Started tickers queue again after each tick, so [run] returns once all are stopped.
+//!
+//! APIs relying on JavaScript, like [time::sleep](crate::time::sleep), timers of
+//! [Debounced](crate::debounce::Debounced) and [Ticker::abort_signal](crate::Ticker::abort_signal),
+//! fail with [TickerError::Unavailable](crate::TickerError::Unavailable) on native targets.
+//!
+//! ```ignore
+//! let ticker = AutoTickerFactory::new(move || {
+//!     // ...
+//! })?;
+//! ticker.start()?;
+//! native::run();
+//! ```
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+type TaskQueue = VecDeque<Box<dyn FnOnce()>>;
+
+thread_local! {
+    static QUEUE: RefCell<TaskQueue> = RefCell::new(VecDeque::new());
+}
+
+pub(crate) fn enqueue(task: Box<dyn FnOnce()>) {
+    QUEUE.with(|queue| queue.borrow_mut().push_back(task));
+}
+
+/// Run tasks queued before this call, returning how many were run.
+///
+/// Tasks queued meanwhile are left for next call.
+pub fn run_once() -> usize {
+    let queue = QUEUE.with(|queue| std::mem::take(&mut *queue.borrow_mut()));
+    let count = queue.len();
+    for task in queue {
+        task();
+    }
+    count
+}
+
+/// Run queued tasks until none is left.
+pub fn run() {
+    while run_once() > 0 {}
+}
+
+/// Run queued tasks until none is left or `duration` elapsed,
+/// returning whether any task is left.
+pub fn run_for(duration: Duration) -> bool {
+    let deadline = Instant::now() + duration;
+    while Instant::now() < deadline {
+        if run_once() == 0 {
+            return false;
+        }
+    }
+    !is_idle()
+}
+
+/// Whether no task is queued.
+pub fn is_idle() -> bool {
+    QUEUE.with(|queue| queue.borrow().is_empty())
+}
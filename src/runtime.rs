@@ -0,0 +1,58 @@
+use crate::bindings::{get_global, has_global};
+use wasm_bindgen::__rt::Lazy;
+
+static CURRENT: Lazy<Runtime> = Lazy::new(Runtime::detect);
+
+/// JavaScript runtime hosting current WebAssembly instance.
+///
+/// Runtimes differ in available scheduling primitives and timer tokens:
+///
+/// | Runtime |    setImmediate    | MessageChannel | setTimeout token |
+/// |:-------:|:------------------:|:--------------:|:----------------:|
+/// | Browser |         -          |       *        |      Number      |
+/// |  Node   |     `Immediate`    |       *        |    `Timeout`     |
+/// |  Deno   |         -          |       *        |      Number      |
+/// |   Bun   |     `Immediate`    |       *        |    `Timeout`     |
+/// | Worker  |         -          |       *        |      Number      |
+///
+/// [AutoTicker](crate::ticker::AutoTicker) prefers
+/// [ImmediateTicker](crate::ticker::ImmediateTicker) in Bun,
+/// where it is the fastest primitive and ports of `MessageChannel` are delivered slower,
+/// and never selects a `setImmediate` polyfill in Deno.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Runtime {
+    /// Browser Window context.
+    Browser,
+    Node,
+    Deno,
+    Bun,
+    /// Dedicated, shared or service worker of browsers.
+    Worker,
+    /// Other embedders, and native targets.
+    Unknown,
+}
+
+impl Runtime {
+    /// Detected once, later calls return cached result.
+    pub fn current() -> Self {
+        *CURRENT
+    }
+
+    /// Deno and Bun are checked first, as both define `process.versions.node`
+    /// for Node compatibility.
+    fn detect() -> Self {
+        if has_global("Deno") {
+            Self::Deno
+        } else if has_global("Bun") {
+            Self::Bun
+        } else if get_global(&["process", "versions", "node"]).map_or(false, |v| v.is_string()) {
+            Self::Node
+        } else if has_global("WorkerGlobalScope") {
+            Self::Worker
+        } else if has_global("document") {
+            Self::Browser
+        } else {
+            Self::Unknown
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::abort::AbortListeners;
+use crate::State;
+use wasm_bindgen::__rt::{Ref, WasmRefCell};
+
+/// Observers return whether to be removed.
+type Observers = Vec<Box<dyn FnMut(&State) -> bool>>;
+
+/// [State] of a ticker, notifying observers on change.
+pub(crate) struct StateCell {
+    state: WasmRefCell<State>,
+    observers: WasmRefCell<Observers>,
+    /// Listeners of [Ticker::stop_on_abort](crate::Ticker::stop_on_abort).
+    pub(crate) aborts: AbortListeners,
+}
+
+impl StateCell {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: WasmRefCell::new(State::Stopped),
+            observers: WasmRefCell::new(Vec::new()),
+            aborts: AbortListeners::default(),
+        }
+    }
+
+    pub(crate) fn borrow(&self) -> Ref<'_, State> {
+        self.state.borrow()
+    }
+
+    pub(crate) fn get(&self) -> State {
+        self.state.borrow().clone()
+    }
+
+    /// Set state and notify observers if changed.
+    ///
+    /// Changes made by observers themselves are not reported again.
+    pub(crate) fn set(&self, state: State) {
+        if *self.state.borrow() == state {
+            return;
+        }
+        *self.state.borrow_mut() = state;
+        let observers = std::mem::take(&mut *self.observers.borrow_mut());
+        // Observers may start or stop the ticker, so nothing is borrowed while calling them
+        let state = self.get();
+        let mut kept = Vec::with_capacity(observers.len());
+        for mut observer in observers {
+            if !observer(&state) {
+                kept.push(observer);
+            }
+        }
+        let mut observers = kept;
+        let mut current = self.observers.borrow_mut();
+        observers.append(&mut current);
+        *current = observers;
+    }
+
+    /// Clear [State::Error] to [State::Stopped], returning whether it was an error.
+    pub(crate) fn reset(&self) -> bool {
+        let is_error = matches!(*self.state.borrow(), State::Error(_));
+        if is_error {
+            self.set(State::Stopped);
+        }
+        is_error
+    }
+
+    /// Set [State::Started] to [State::Stopped], as [Ticker::stop](crate::Ticker::stop) of most tickers.
+    pub(crate) fn stop(&self) {
+        if self.borrow().eq(&State::Started) {
+            self.set(State::Stopped);
+        }
+    }
+
+    pub(crate) fn observe(&self, mut observer: Box<dyn FnMut(&State)>) {
+        self.observe_until(Box::new(move |state| {
+            observer(state);
+            false
+        }));
+    }
+
+    /// Like [StateCell::observe], but `observer` is removed once it returns `true`.
+    pub(crate) fn observe_until(&self, observer: Box<dyn FnMut(&State) -> bool>) {
+        self.observers.borrow_mut().push(observer);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{State, Ticker, TickerError, TickerFactory};
+use futures_core::Stream;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen::__rt::WasmRefCell;
+
+/// What [TickStream] does with ticks while consumer is slower than ticker.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Backpressure {
+    /// Discard ticks arriving while a previous one is not consumed yet.
+    Drop,
+    /// Merge ticks arriving while a previous one is not consumed yet,
+    /// counting them in [TickInfo::count].
+    Coalesce,
+    /// Stop underlying ticker on each tick, restart it when polled again.
+    Pause,
+}
+
+/// Item of [TickStream].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TickInfo {
+    /// Sequence number of the tick, starting from 1.
+    pub tick: u64,
+    /// Ticks merged into this item, greater than 1 only with [Backpressure::Coalesce].
+    pub count: u64,
+}
+
+struct Shared<T> {
+    seq: u64,
+    pending: Option<TickInfo>,
+    waker: Option<Waker>,
+    closed: bool,
+    /// Clone of ticker to pause itself, only with [Backpressure::Pause].
+    ticker: Option<T>,
+}
+
+/// Async [Stream] of ticks of [TickerFactory::Output].
+///
+/// Underlying ticker starts on first poll, and stops when [TickStream] is dropped.
+/// The stream ends when closed, or underlying ticker failed to start or run.
+///
+/// ```ignore
+/// let mut ticks = TickStream::<AutoTickerFactory>::new(Backpressure::Coalesce)?;
+/// while let Some(info) = ticks.next().await {
+///     // ...
+/// }
+/// ```
+pub struct TickStream<F: TickerFactory> {
+    ticker: F::Output,
+    shared: Rc<WasmRefCell<Shared<F::Output>>>,
+}
+
+impl<F: TickerFactory> TickStream<F>
+where
+    F::Output: 'static,
+{
+    pub fn new(backpressure: Backpressure) -> Result<Self, TickerError> {
+        let shared: Rc<WasmRefCell<Shared<F::Output>>> = Rc::new(WasmRefCell::new(Shared {
+            seq: 0,
+            pending: None,
+            waker: None,
+            closed: false,
+            ticker: None,
+        }));
+
+        let shared_ = shared.clone();
+        let ticker = F::new(move || {
+            let mut shared = shared_.borrow_mut();
+            shared.seq += 1;
+            let tick = shared.seq;
+            match (&mut shared.pending, backpressure) {
+                (Some(_), Backpressure::Drop) => (),
+                (Some(info), Backpressure::Coalesce) => {
+                    info.tick = tick;
+                    info.count += 1;
+                }
+                (pending, _) => *pending = Some(TickInfo { tick, count: 1 }),
+            }
+            let ticker = shared.ticker.clone();
+            let waker = shared.waker.take();
+            // Not borrowed while stopping or waking, either may poll the stream again
+            drop(shared);
+            if let Some(ticker) = ticker {
+                ticker.stop();
+            }
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        })?;
+
+        // Wake consumer to end the stream on error
+        let shared_ = shared.clone();
+        ticker.on_state_change(Box::new(move |state| {
+            if let State::Error(_) = state {
+                let waker = shared_.borrow_mut().waker.take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }
+        }));
+
+        if backpressure == Backpressure::Pause {
+            shared.borrow_mut().ticker = Some(ticker.clone());
+        }
+        Ok(Self { ticker, shared })
+    }
+
+    /// Underlying ticker.
+    ///
+    /// Use [TickStream::close] instead of [Ticker::stop] to end the stream,
+    /// a stopped ticker is restarted on next poll.
+    pub fn ticker(&self) -> &F::Output {
+        &self.ticker
+    }
+
+    /// Stop underlying ticker and end the stream after pending tick consumed.
+    pub fn close(&self) {
+        self.ticker.stop();
+        let waker = {
+            let mut shared = self.shared.borrow_mut();
+            shared.closed = true;
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<F: TickerFactory> Unpin for TickStream<F> {}
+
+impl<F: TickerFactory> Stream for TickStream<F> {
+    type Item = TickInfo;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut shared = self.shared.borrow_mut();
+        if let Some(info) = shared.pending.take() {
+            return Poll::Ready(Some(info));
+        }
+        if shared.closed {
+            return Poll::Ready(None);
+        }
+        shared.waker = Some(cx.waker().clone());
+        drop(shared);
+
+        match self.ticker.state() {
+            State::Started => Poll::Pending,
+            State::Stopped => match self.ticker.start() {
+                Ok(()) => Poll::Pending,
+                Err(_) => Poll::Ready(None),
+            },
+            State::Error(_) => Poll::Ready(None),
+        }
+    }
+}
+
+impl<F: TickerFactory> Drop for TickStream<F> {
+    fn drop(&mut self) {
+        self.ticker.stop();
+        // Break reference cycle of paused ticker
+        self.shared.borrow_mut().ticker.take();
+    }
+}
@@ -0,0 +1,275 @@
+use crate::bindings::{memory_i32_index, memory_i32_view, promise_then, wait_async_promise};
+use crate::ticker::TimeoutTicker;
+use crate::{State, Ticker, TickerError};
+use js_sys::Atomics;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicI32, AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen::prelude::*;
+
+const STOPPED: u8 = 0;
+const STARTED: u8 = 1;
+const ERROR: u8 = 2;
+
+/// [TickerError] sendable to other threads, JavaScript values are kept as messages.
+enum SentError {
+    AlreadyStarted,
+    NoBackendAvailable,
+    NotRegistered(String),
+    Unavailable(&'static str),
+    ScheduleFailed(String),
+    MessageError(String),
+    CallbackPanicked,
+    Cancelled,
+    Js(String),
+}
+
+impl SentError {
+    fn new(error: TickerError) -> Self {
+        let message = |e: JsValue| e.as_string().unwrap_or_else(|| format!("{:?}", e));
+        match error {
+            TickerError::AlreadyStarted => SentError::AlreadyStarted,
+            TickerError::NoBackendAvailable => SentError::NoBackendAvailable,
+            TickerError::NotRegistered(name) => SentError::NotRegistered(name),
+            TickerError::Unavailable(api) => SentError::Unavailable(api),
+            TickerError::ScheduleFailed(e) => SentError::ScheduleFailed(message(e)),
+            TickerError::MessageError(e) => SentError::MessageError(message(e)),
+            TickerError::CallbackPanicked => SentError::CallbackPanicked,
+            TickerError::Cancelled => SentError::Cancelled,
+            TickerError::Js(e) => SentError::Js(message(e)),
+        }
+    }
+
+    fn to_error(&self) -> TickerError {
+        match self {
+            SentError::AlreadyStarted => TickerError::AlreadyStarted,
+            SentError::NoBackendAvailable => TickerError::NoBackendAvailable,
+            SentError::NotRegistered(name) => TickerError::NotRegistered(name.clone()),
+            SentError::Unavailable(api) => TickerError::Unavailable(api),
+            SentError::ScheduleFailed(m) => TickerError::ScheduleFailed(JsValue::from_str(m)),
+            SentError::MessageError(m) => TickerError::MessageError(JsValue::from_str(m)),
+            SentError::CallbackPanicked => TickerError::CallbackPanicked,
+            SentError::Cancelled => TickerError::Cancelled,
+            SentError::Js(m) => TickerError::Js(JsValue::from_str(m)),
+        }
+    }
+}
+
+/// Result of a request, sent back by owning thread.
+struct Reply {
+    result: Mutex<Option<Result<(), SentError>>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Reply {
+    fn send(&self, result: Result<(), TickerError>) {
+        *self.result.lock().unwrap() = Some(result.map_err(SentError::new));
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by [TickerHandle::start] and [TickerHandle::start_immediate],
+/// resolved once owning thread processed the request.
+///
+/// The request is sent at once, awaiting only receives its result.
+pub struct Response {
+    reply: Arc<Reply>,
+}
+
+impl Future for Response {
+    type Output = Result<(), TickerError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Registered before checking, so a result sent meanwhile wakes it
+        *self.reply.waker.lock().unwrap() = Some(cx.waker().clone());
+        match self.reply.result.lock().unwrap().take() {
+            Some(result) => Poll::Ready(result.map_err(|e| e.to_error())),
+            None => Poll::Pending,
+        }
+    }
+}
+
+enum Request {
+    Start(Arc<Reply>),
+    StartImmediate(Arc<Reply>),
+    Stop,
+    Reset,
+}
+
+struct Shared {
+    /// Bumped and notified on every request, waited by owning thread.
+    seq: AtomicI32,
+    requests: Mutex<VecDeque<Request>>,
+    /// Mirrored [State] of the ticker.
+    state: AtomicU8,
+    error: Mutex<Option<SentError>>,
+    handles: AtomicUsize,
+}
+
+impl Shared {
+    fn request(&self, request: Request) {
+        self.requests.lock().unwrap().push_back(request);
+        self.notify();
+    }
+
+    fn request_reply(&self, request: impl FnOnce(Arc<Reply>) -> Request) -> Response {
+        let reply = Arc::new(Reply {
+            result: Mutex::new(None),
+            waker: Mutex::new(None),
+        });
+        self.request(request(reply.clone()));
+        Response { reply }
+    }
+
+    fn notify(&self) {
+        self.seq.fetch_add(1, Ordering::SeqCst);
+        // Fails only if not shared memory, then owning thread is polling
+        let _ = Atomics::notify(&memory_i32_view(), memory_i32_index(&self.seq));
+    }
+
+    fn mirror(&self, state: State) {
+        let state = match state {
+            State::Started => STARTED,
+            State::Stopped => STOPPED,
+            State::Error(e) => {
+                *self.error.lock().unwrap() = Some(SentError::new(e));
+                ERROR
+            }
+        };
+        self.state.store(state, Ordering::SeqCst);
+    }
+}
+
+struct Listener<T> {
+    ticker: T,
+    shared: Arc<Shared>,
+}
+
+impl<T: Ticker + 'static> Listener<T> {
+    /// Handle pending requests, returning `false` once all handles are dropped.
+    fn process(&self) -> bool {
+        let requests: Vec<_> = self.shared.requests.lock().unwrap().drain(..).collect();
+        for request in requests {
+            match request {
+                Request::Start(reply) => reply.send(self.ticker.start()),
+                Request::StartImmediate(reply) => reply.send(self.ticker.start_immediate()),
+                Request::Stop => self.ticker.stop(),
+                Request::Reset => self.ticker.reset(),
+            }
+        }
+        // For tickers keeping the default `on_state_change`
+        self.shared.mirror(self.ticker.state());
+        self.shared.handles.load(Ordering::SeqCst) > 0
+    }
+
+    /// Wait for requests by `Atomics.waitAsync`,
+    /// or poll with `setTimeout` if not available or memory is not shared.
+    fn listen(self: Rc<Self>) {
+        let seq = self.shared.seq.load(Ordering::SeqCst);
+        if !self.process() {
+            return;
+        }
+        let index = memory_i32_index(&self.shared.seq);
+        let promise = Atomics::wait_async(&memory_i32_view(), index, seq)
+            .and_then(|result| wait_async_promise(&result));
+        if let Ok(Some(promise)) = promise {
+            let this = self.clone();
+            let listen = Closure::once_into_js(move |_: JsValue| this.listen());
+            if promise_then(&promise, &listen).is_ok() {
+                return;
+            }
+        }
+        // Requested while processing, or waiting not available
+        let _ = TimeoutTicker::spawn(move || self.listen());
+    }
+}
+
+/// Thread-safe handle of a [Ticker], which can be sent to other threads
+/// of a `+atomics` build, requires `atomics` feature.
+///
+/// Requests are forwarded to the thread owning the ticker,
+/// woken by `Atomics.waitAsync`, or polled with `setTimeout` if not available.
+/// The ticker is dropped on its thread after all handles are dropped.
+pub struct TickerHandle {
+    shared: Arc<Shared>,
+}
+
+impl TickerHandle {
+    /// Take ownership of `ticker`, must be called on the thread running it.
+    pub fn new<T: Ticker + 'static>(ticker: T) -> Self {
+        let shared = Arc::new(Shared {
+            seq: AtomicI32::new(0),
+            requests: Mutex::new(VecDeque::new()),
+            state: AtomicU8::new(STOPPED),
+            error: Mutex::new(None),
+            handles: AtomicUsize::new(1),
+        });
+        let shared_ = shared.clone();
+        ticker.on_state_change(Box::new(move |state| shared_.mirror(state.clone())));
+        Rc::new(Listener {
+            ticker,
+            shared: shared.clone(),
+        })
+        .listen();
+        Self { shared }
+    }
+
+    /// Last known state, mirrored on each change reported by [Ticker::on_state_change],
+    /// and after each request processed by owning thread.
+    ///
+    /// JavaScript values of error are replaced by their messages.
+    pub fn state(&self) -> State {
+        match self.shared.state.load(Ordering::SeqCst) {
+            STARTED => State::Started,
+            ERROR => match &*self.shared.error.lock().unwrap() {
+                Some(error) => State::Error(error.to_error()),
+                None => State::Stopped,
+            },
+            _ => State::Stopped,
+        }
+    }
+
+    /// Request [Ticker::start] on owning thread, its result is received by awaiting.
+    pub fn start(&self) -> Response {
+        self.shared.request_reply(Request::Start)
+    }
+
+    /// Request [Ticker::start_immediate] on owning thread, its result is received by awaiting.
+    pub fn start_immediate(&self) -> Response {
+        self.shared.request_reply(Request::StartImmediate)
+    }
+
+    /// Request [Ticker::stop] on owning thread.
+    pub fn stop(&self) {
+        self.shared.request(Request::Stop);
+    }
+
+    /// Request [Ticker::reset] on owning thread.
+    pub fn reset(&self) {
+        self.shared.request(Request::Reset);
+    }
+}
+
+impl Clone for TickerHandle {
+    fn clone(&self) -> Self {
+        self.shared.handles.fetch_add(1, Ordering::SeqCst);
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for TickerHandle {
+    fn drop(&mut self) {
+        if self.shared.handles.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Wake owning thread to drop the ticker
+            self.shared.notify();
+        }
+    }
+}
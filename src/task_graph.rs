@@ -0,0 +1,293 @@
+use crate::completion::CompletionCell;
+use crate::ticker::BoxedTicker;
+use crate::{State, Ticker, TickerError, TickerFactory};
+use std::any::Any;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// Error returned by a node of [TaskGraph].
+pub type NodeError = Box<dyn std::error::Error>;
+
+type NodeFn = Box<dyn FnOnce(&GraphResults) -> Result<Box<dyn Any>, NodeError>>;
+
+/// Untyped identifier of a node, declared as dependency by [TaskGraph::add].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct NodeId(usize);
+
+/// Typed identifier of a node returning `T`, see [GraphResults::get].
+pub struct Node<T> {
+    id: NodeId,
+    _output: PhantomData<fn() -> T>,
+}
+
+impl<T> Node<T> {
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+}
+
+impl<T> Clone for Node<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Node<T> {}
+
+/// Execution state of a node.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NodeState {
+    /// Not executed yet.
+    Pending,
+    Done,
+    /// Returned an error, kept as message.
+    Failed(String),
+    /// Not executed as a dependency failed.
+    Cancelled,
+}
+
+/// Outputs and states of nodes of a [TaskGraph].
+///
+/// Passed to each node to read outputs of its dependencies.
+pub struct GraphResults {
+    outputs: Vec<Option<Box<dyn Any>>>,
+    states: Vec<NodeState>,
+}
+
+impl GraphResults {
+    /// Output of `node` if done.
+    pub fn get<T: 'static>(&self, node: Node<T>) -> Option<&T> {
+        self.outputs.get(node.id.0)?.as_ref()?.downcast_ref()
+    }
+
+    /// Move out output of `node` if done.
+    pub fn take<T: 'static>(&mut self, node: Node<T>) -> Option<T> {
+        let output = self.outputs.get_mut(node.id.0)?.take()?;
+        output.downcast().ok().map(|output| *output)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `id` is not of this graph.
+    pub fn state(&self, id: NodeId) -> &NodeState {
+        &self.states[id.0]
+    }
+
+    /// Whether all nodes are done.
+    pub fn is_success(&self) -> bool {
+        self.states.iter().all(|state| *state == NodeState::Done)
+    }
+}
+
+/// Directed acyclic graph of tasks executed across ticks.
+///
+/// Dependencies are declared by identifiers of nodes added before,
+/// so the graph is acyclic by construction. Nodes run in topological order
+/// once all dependencies are done, and a failed node cancels its dependents.
+///
+/// ```ignore
+/// let mut graph = TaskGraph::new();
+/// let text = graph.add(&[], |_| Ok(fetch_cached("shader.wgsl")?));
+/// let module = graph.add(&[text.id()], move |r| Ok(compile(r.get(text).unwrap())?));
+/// let results = graph.run::<AutoTickerFactory>(1)?.await?;
+/// assert!(results.is_success());
+/// ```
+#[derive(Default)]
+pub struct TaskGraph {
+    tasks: Vec<NodeFn>,
+    dependencies: Vec<Vec<NodeId>>,
+}
+
+impl TaskGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node running `task` after all of `dependencies`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dependency is not of this graph.
+    pub fn add<T: 'static>(
+        &mut self,
+        dependencies: &[NodeId],
+        task: impl FnOnce(&GraphResults) -> Result<T, NodeError> + 'static,
+    ) -> Node<T> {
+        let id = NodeId(self.tasks.len());
+        assert!(
+            dependencies.iter().all(|dep| dep.0 < id.0),
+            "dependency is not of this graph"
+        );
+        self.tasks.push(Box::new(move |results: &GraphResults| {
+            task(results).map(|output| Box::new(output) as Box<dyn Any>)
+        }));
+        self.dependencies.push(dependencies.to_vec());
+        Node {
+            id,
+            _output: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Execute up to `per_tick` ready nodes on each tick of [TickerFactory::Output],
+    /// starting on next tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_tick` is zero.
+    pub fn run<F>(self, per_tick: usize) -> Result<GraphRun, TickerError>
+    where
+        F: TickerFactory,
+        F::Output: 'static,
+    {
+        assert!(per_tick > 0, "`per_tick` must be non-zero.");
+        let completion = Rc::new(CompletionCell::new());
+
+        let completion_ = completion.clone();
+        let mut exec = Some(Exec::new(self));
+        let ticker = F::new(move || {
+            if let Some(current) = &mut exec {
+                current.step(per_tick);
+                if current.is_finished() {
+                    let results = exec.take().unwrap().results;
+                    completion_.finish(Ok(results));
+                }
+            }
+        })?;
+
+        let completion_ = completion.clone();
+        ticker.on_state_change(Box::new(move |state| {
+            if let State::Error(e) = state {
+                completion_.finish(Err(e.clone()));
+            }
+        }));
+        ticker.start()?;
+        completion.set_ticker(BoxedTicker::new(ticker));
+        Ok(GraphRun { completion })
+    }
+}
+
+struct Exec {
+    tasks: Vec<Option<NodeFn>>,
+    dependents: Vec<Vec<usize>>,
+    /// Count of dependencies not done yet.
+    waiting: Vec<usize>,
+    ready: VecDeque<usize>,
+    settled: usize,
+    results: GraphResults,
+}
+
+impl Exec {
+    fn new(graph: TaskGraph) -> Self {
+        let n = graph.tasks.len();
+        let mut dependents = vec![Vec::new(); n];
+        let mut waiting = vec![0; n];
+        let mut ready = VecDeque::new();
+        for (id, dependencies) in graph.dependencies.iter().enumerate() {
+            waiting[id] = dependencies.len();
+            for dep in dependencies {
+                dependents[dep.0].push(id);
+            }
+            if dependencies.is_empty() {
+                ready.push_back(id);
+            }
+        }
+        Self {
+            tasks: graph.tasks.into_iter().map(Some).collect(),
+            dependents,
+            waiting,
+            ready,
+            settled: 0,
+            results: GraphResults {
+                outputs: (0..n).map(|_| None).collect(),
+                states: vec![NodeState::Pending; n],
+            },
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.settled == self.tasks.len()
+    }
+
+    fn step(&mut self, per_tick: usize) {
+        for _ in 0..per_tick {
+            let id = match self.ready.pop_front() {
+                Some(id) => id,
+                None => return,
+            };
+            let task = match self.tasks[id].take() {
+                Some(task) => task,
+                None => continue,
+            };
+            self.settled += 1;
+            match task(&self.results) {
+                Ok(output) => {
+                    self.results.outputs[id] = Some(output);
+                    self.results.states[id] = NodeState::Done;
+                    for i in 0..self.dependents[id].len() {
+                        let dependent = self.dependents[id][i];
+                        self.waiting[dependent] -= 1;
+                        if self.waiting[dependent] == 0 {
+                            self.ready.push_back(dependent);
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.results.states[id] = NodeState::Failed(e.to_string());
+                    self.cancel_dependents(id);
+                }
+            }
+        }
+    }
+
+    fn cancel_dependents(&mut self, id: usize) {
+        let mut stack = self.dependents[id].clone();
+        while let Some(id) = stack.pop() {
+            if self.tasks[id].take().is_some() {
+                self.settled += 1;
+                self.results.states[id] = NodeState::Cancelled;
+                stack.extend_from_slice(&self.dependents[id]);
+            }
+        }
+    }
+}
+
+/// Future returned by [TaskGraph::run], resolving with [GraphResults] once all nodes settled.
+///
+/// Resolves with error if the ticker failed, or [TickerError::Cancelled] after [GraphRun::cancel].
+/// Execution continues if dropped.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GraphRun {
+    completion: Rc<CompletionCell<Result<GraphResults, TickerError>>>,
+}
+
+impl GraphRun {
+    /// Drop nodes not executed yet, resolving with [TickerError::Cancelled] if not finished.
+    pub fn cancel(&self) {
+        self.completion.finish(Err(TickerError::Cancelled));
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.completion.is_finished()
+    }
+}
+
+impl Future for GraphRun {
+    type Output = Result<GraphResults, TickerError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = self.completion.poll_take(cx);
+        result.map(|result| result.unwrap_or(Err(TickerError::Cancelled)))
+    }
+}
@@ -1,17 +1,20 @@
 use super::{message_channel::MessageChannelTicker, timers::*};
-use crate::{State, Ticker};
-use wasm_bindgen::prelude::*;
+use crate::{State, Ticker, TickerError};
+use web_sys::AbortSignal;
 
 /// Constructed by [AutoTickerFactory](crate::factory::AutoTickerFactory).
 ///
 /// Wrapping one of [ImmediateTicker], [TimeoutTicker],
-/// [AnimationFrameTicker] or [MessageChannelTicker].
+/// [AnimationFrameTicker] or [MessageChannelTicker],
+/// or [NativeTicker](super::NativeTicker) on native targets.
 #[derive(Clone, Eq, PartialEq)]
 pub enum AutoTicker {
     MessageChannel(MessageChannelTicker),
     Timeout(TimeoutTicker),
     Immediate(ImmediateTicker),
     AnimationFrame(AnimationFrameTicker),
+    #[cfg(not(target_family = "wasm"))]
+    Native(super::NativeTicker),
 }
 
 impl Ticker for AutoTicker {
@@ -21,24 +24,30 @@ impl Ticker for AutoTicker {
             AutoTicker::Timeout(t) => t.state(),
             AutoTicker::Immediate(t) => t.state(),
             AutoTicker::AnimationFrame(t) => t.state(),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.state(),
         }
     }
 
-    fn start(&self) -> Result<(), JsValue> {
+    fn start(&self) -> Result<(), TickerError> {
         match self {
             AutoTicker::MessageChannel(t) => t.start(),
             AutoTicker::Timeout(t) => t.start(),
             AutoTicker::Immediate(t) => t.start(),
             AutoTicker::AnimationFrame(t) => t.start(),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.start(),
         }
     }
 
-    fn start_immediate(&self) -> Result<(), JsValue> {
+    fn start_immediate(&self) -> Result<(), TickerError> {
         match self {
             AutoTicker::MessageChannel(t) => t.start_immediate(),
             AutoTicker::Timeout(t) => t.start_immediate(),
             AutoTicker::Immediate(t) => t.start_immediate(),
             AutoTicker::AnimationFrame(t) => t.start_immediate(),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.start_immediate(),
         }
     }
 
@@ -48,17 +57,65 @@ impl Ticker for AutoTicker {
             AutoTicker::Timeout(t) => t.stop(),
             AutoTicker::Immediate(t) => t.stop(),
             AutoTicker::AnimationFrame(t) => t.stop(),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.stop(),
         }
     }
 
-    fn spawn(task: impl FnOnce() + 'static) -> Result<(), JsValue> {
+    fn reset(&self) {
+        match self {
+            AutoTicker::MessageChannel(t) => t.reset(),
+            AutoTicker::Timeout(t) => t.reset(),
+            AutoTicker::Immediate(t) => t.reset(),
+            AutoTicker::AnimationFrame(t) => t.reset(),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.reset(),
+        }
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        match self {
+            AutoTicker::MessageChannel(t) => t.on_state_change(observer),
+            AutoTicker::Timeout(t) => t.on_state_change(observer),
+            AutoTicker::Immediate(t) => t.on_state_change(observer),
+            AutoTicker::AnimationFrame(t) => t.on_state_change(observer),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.on_state_change(observer),
+        }
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        match self {
+            AutoTicker::MessageChannel(t) => t.stop_on_abort(signal),
+            AutoTicker::Timeout(t) => t.stop_on_abort(signal),
+            AutoTicker::Immediate(t) => t.stop_on_abort(signal),
+            AutoTicker::AnimationFrame(t) => t.stop_on_abort(signal),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.stop_on_abort(signal),
+        }
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        match self {
+            AutoTicker::MessageChannel(t) => t.abort_signal(),
+            AutoTicker::Timeout(t) => t.abort_signal(),
+            AutoTicker::Immediate(t) => t.abort_signal(),
+            AutoTicker::AnimationFrame(t) => t.abort_signal(),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => t.abort_signal(),
+        }
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
         use crate::factory::{SelectedTicker, SELECTED_TICKER};
         match *SELECTED_TICKER {
             SelectedTicker::MessageChannel => MessageChannelTicker::spawn(task),
             SelectedTicker::Immediate => ImmediateTicker::spawn(task),
             SelectedTicker::Timeout => TimeoutTicker::spawn(task),
             SelectedTicker::AnimationFrame => AnimationFrameTicker::spawn(task),
-            SelectedTicker::None => Err(JsValue::from_str("No available implementation detected")),
+            #[cfg(not(target_family = "wasm"))]
+            SelectedTicker::Native => super::NativeTicker::spawn(task),
+            SelectedTicker::None => Err(TickerError::NoBackendAvailable),
         }
     }
 }
@@ -70,6 +127,8 @@ impl From<AutoTicker> for Box<dyn Ticker> {
             AutoTicker::Timeout(t) => Box::new(t),
             AutoTicker::Immediate(t) => Box::new(t),
             AutoTicker::AnimationFrame(t) => Box::new(t),
+            #[cfg(not(target_family = "wasm"))]
+            AutoTicker::Native(t) => Box::new(t),
         }
     }
 }
@@ -0,0 +1,144 @@
+use crate::abort;
+use crate::state::StateCell;
+use crate::{NamedTicker, ScheduleBackend, State, Ticker, TickerError, TimerTicker};
+use std::rc::Rc;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+use web_sys::AbortSignal;
+
+/// Constructed by [BackendTickerFactory](crate::factory::BackendTickerFactory).
+///
+/// Queues its callback by [ScheduleBackend::schedule] on each tick,
+/// and cancels queued one on [Ticker::stop].
+pub struct BackendTicker<B: ScheduleBackend> {
+    pub(crate) token: Rc<WasmRefCell<Option<B::Token>>>,
+    pub(crate) state: Rc<StateCell>,
+    /// Called with first argument passed by backend, e.g. timestamp of `requestAnimationFrame`.
+    pub(crate) cb: Rc<Closure<dyn FnMut(JsValue)>>,
+}
+
+impl<B: ScheduleBackend> BackendTicker<B> {
+    pub(crate) fn function(&self) -> &js_sys::Function {
+        self.cb.as_ref().as_ref().unchecked_ref()
+    }
+
+    fn stop_with(state: &StateCell, token: &WasmRefCell<Option<B::Token>>) {
+        if state.borrow().eq(&State::Started) {
+            if let Some(token) = token.borrow_mut().take() {
+                B::cancel(token)
+            }
+            state.set(State::Stopped);
+        }
+    }
+}
+
+impl<B: ScheduleBackend> Clone for BackendTicker<B> {
+    fn clone(&self) -> Self {
+        Self {
+            token: self.token.clone(),
+            state: self.state.clone(),
+            cb: self.cb.clone(),
+        }
+    }
+}
+
+impl<B: ScheduleBackend> PartialEq for BackendTicker<B> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl<B: ScheduleBackend> Eq for BackendTicker<B> {}
+
+impl<B: ScheduleBackend> Drop for BackendTicker<B> {
+    fn drop(&mut self) {
+        if let Some(token) = self.token.borrow_mut().take() {
+            B::cancel(token)
+        }
+    }
+}
+
+impl<B: ScheduleBackend> Ticker for BackendTicker<B> {
+    fn state(&self) -> State {
+        self.state.borrow().clone()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        match *self.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.state.set(State::Started);
+        match B::schedule(self.function()) {
+            Ok(token) => {
+                self.token.borrow_mut().replace(token);
+                Ok(())
+            }
+            Err(e) => {
+                let e = TickerError::ScheduleFailed(e);
+                self.state.set(State::Error(e.clone()));
+                Err(e)
+            }
+        }
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        match *self.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.state.set(State::Started);
+        self.function().call0(&JsValue::null())?;
+        Ok(())
+    }
+
+    fn stop(&self) {
+        Self::stop_with(&self.state, &self.token)
+    }
+
+    fn reset(&self) {
+        if self.state.reset() {
+            self.token.borrow_mut().take();
+        }
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.state.observe(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let state = Rc::downgrade(&self.state);
+        let token = Rc::downgrade(&self.token);
+        abort::on_abort(&self.state.aborts, signal, move || {
+            if let (Some(state), Some(token)) = (state.upgrade(), token.upgrade()) {
+                Self::stop_with(&state, &token);
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        abort::abort_signal(&self.state)
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        let cb = Closure::once_into_js(task);
+        B::schedule(cb.unchecked_ref()).map_err(TickerError::ScheduleFailed)?;
+        Ok(())
+    }
+}
+
+impl<B: ScheduleBackend> NamedTicker for BackendTicker<B> {
+    fn check() -> bool {
+        B::available()
+    }
+}
+
+impl<B: ScheduleBackend> TimerTicker for BackendTicker<B> {
+    type Token = B::Token;
+
+    fn token(&self) -> Option<Self::Token> {
+        self.token.borrow().clone()
+    }
+}
@@ -0,0 +1,82 @@
+use super::AutoTicker;
+use crate::abort::{self, AbortListeners};
+use crate::{State, Ticker, TickerError};
+use std::rc::Rc;
+use web_sys::AbortSignal;
+
+/// Type-erased [Ticker], constructed by [DynTickerFactory](crate::factory::DynTickerFactory).
+///
+/// Clones share the same ticker, and compare equal.
+#[derive(Clone)]
+pub struct BoxedTicker {
+    ticker: Rc<dyn Ticker>,
+    aborts: Rc<AbortListeners>,
+}
+
+impl BoxedTicker {
+    pub fn new(ticker: impl Ticker + 'static) -> Self {
+        Self {
+            ticker: Rc::new(ticker),
+            aborts: Rc::default(),
+        }
+    }
+}
+
+impl From<AutoTicker> for BoxedTicker {
+    fn from(value: AutoTicker) -> Self {
+        Self::new(value)
+    }
+}
+
+impl PartialEq for BoxedTicker {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.ticker, &other.ticker)
+    }
+}
+
+impl Eq for BoxedTicker {}
+
+impl Ticker for BoxedTicker {
+    fn state(&self) -> State {
+        self.ticker.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        self.ticker.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        self.ticker.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.ticker.stop()
+    }
+
+    fn reset(&self) {
+        self.ticker.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.ticker.on_state_change(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let ticker = Rc::downgrade(&self.ticker);
+        abort::on_abort(&self.aborts, signal, move || {
+            if let Some(ticker) = ticker.upgrade() {
+                ticker.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        self.ticker.abort_signal()
+    }
+
+    /// Same as [AutoTicker::spawn], use [DynTickerFactory::spawn](crate::factory::DynTickerFactory::spawn)
+    /// to spawn by a specific backend.
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        AutoTicker::spawn(task)
+    }
+}
@@ -0,0 +1,283 @@
+use crate::abort;
+use crate::bindings::{
+    __wasm_ticker_function_loop, __wasm_ticker_scheduler, FunctionLoop, Scheduler,
+};
+use crate::state::StateCell;
+use crate::{ScheduleBackend, State, Ticker, TickerError};
+use std::rc::Rc;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+use web_sys::AbortSignal;
+
+/// [Scheduler] of [ScheduleBackend::js_functions], if provided.
+pub(crate) fn scheduler<B: ScheduleBackend>() -> Option<Scheduler> {
+    let (schedule, cancel) = B::js_functions()?;
+    Some(__wasm_ticker_scheduler(&schedule, &cancel))
+}
+
+enum Driver<T> {
+    /// Wrapped ticker applying the function from its task.
+    Ticker(T),
+    /// Applying the function and re-arming in JavaScript, calling back `on_error` with exceptions.
+    Loop {
+        function_loop: FunctionLoop,
+        _on_error: Closure<dyn FnMut(JsValue, bool)>,
+    },
+}
+
+struct Inner<T: Ticker + Clone + 'static> {
+    driver: Driver<T>,
+    /// Exception thrown by the function, until reset.
+    error: Rc<WasmRefCell<Option<JsValue>>>,
+    /// Notifies observers, states of the ticker are mirrored if it reports them.
+    /// Authoritative for [Driver::Loop].
+    state: Rc<StateCell>,
+    /// Clone of ticker to stop itself when the function throws.
+    stopper: Rc<WasmRefCell<Option<T>>>,
+}
+
+impl<T: Ticker + Clone + 'static> Inner<T> {
+    fn stop(&self) {
+        match &self.driver {
+            Driver::Ticker(ticker) => ticker.stop(),
+            Driver::Loop { function_loop, .. } => {
+                if self.state.borrow().eq(&State::Started) {
+                    function_loop.stop();
+                    self.state.set(State::Stopped);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Ticker + Clone + 'static> Drop for Inner<T> {
+    fn drop(&mut self) {
+        // Break reference cycle of the ticker stopping itself
+        self.stopper.borrow_mut().take();
+        if let Driver::Loop { function_loop, .. } = &self.driver {
+            function_loop.close();
+        }
+    }
+}
+
+/// Constructed by [TickerFactory::new_with_function](crate::TickerFactory::new_with_function).
+///
+/// Built-in factories apply the JavaScript function and re-arm in a JavaScript loop,
+/// other ones wrap a ticker applying it by `Function.prototype.apply` from its Rust task.
+/// An exception thrown by the function stops the ticker with [State::Error] of [TickerError::Js].
+///
+/// Clones share the same ticker, and compare equal.
+pub struct FunctionTicker<T: Ticker + Clone + 'static> {
+    inner: Rc<Inner<T>>,
+}
+
+impl<T: Ticker + Clone + 'static> FunctionTicker<T> {
+    pub(crate) fn new(
+        create: impl FnOnce(Box<dyn FnMut()>) -> Result<T, TickerError>,
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<Self, TickerError> {
+        let state = Rc::new(StateCell::new());
+        let error = Rc::new(WasmRefCell::new(None::<JsValue>));
+        let stopper = Rc::new(WasmRefCell::new(None::<T>));
+
+        let error_ = error.clone();
+        let stopper_ = stopper.clone();
+        let ticker = create(Box::new(move || {
+            if let Err(e) = function.apply(&this, &args) {
+                error_.borrow_mut().replace(e);
+                if let Some(ticker) = &*stopper_.borrow() {
+                    ticker.stop();
+                }
+            }
+        }))?;
+
+        let state_ = state.clone();
+        let error_ = error.clone();
+        ticker.on_state_change(Box::new(move |state| match state {
+            State::Stopped => {
+                let error = error_.borrow().clone();
+                match error {
+                    Some(e) => state_.set(State::Error(TickerError::Js(e))),
+                    None => state_.set(State::Stopped),
+                }
+            }
+            state => state_.set(state.clone()),
+        }));
+
+        stopper.borrow_mut().replace(ticker.clone());
+        Ok(Self {
+            inner: Rc::new(Inner {
+                driver: Driver::Ticker(ticker),
+                error,
+                state,
+                stopper,
+            }),
+        })
+    }
+
+    /// Loop re-arming by `scheduler` in JavaScript if any, otherwise wrap a ticker by `create`.
+    pub(crate) fn with_scheduler(
+        scheduler: Option<Scheduler>,
+        create: impl FnOnce(Box<dyn FnMut()>) -> Result<T, TickerError>,
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Result<Self, TickerError> {
+        match scheduler {
+            Some(scheduler) => Ok(Self::new_looped(&scheduler, function, this, args)),
+            None => Self::new(create, function, this, args),
+        }
+    }
+
+    fn new_looped(
+        scheduler: &Scheduler,
+        function: js_sys::Function,
+        this: JsValue,
+        args: js_sys::Array,
+    ) -> Self {
+        let state = Rc::new(StateCell::new());
+        let state_ = state.clone();
+        let on_error = Closure::new(move |e: JsValue, scheduling: bool| {
+            let e = match scheduling {
+                true => TickerError::ScheduleFailed(e),
+                false => TickerError::Js(e),
+            };
+            state_.set(State::Error(e));
+        });
+        let function_loop = __wasm_ticker_function_loop(
+            scheduler,
+            &function,
+            &this,
+            &args,
+            on_error.as_ref().unchecked_ref(),
+        );
+        Self {
+            inner: Rc::new(Inner {
+                driver: Driver::Loop {
+                    function_loop,
+                    _on_error: on_error,
+                },
+                error: Rc::new(WasmRefCell::new(None)),
+                state,
+                stopper: Rc::new(WasmRefCell::new(None)),
+            }),
+        }
+    }
+
+    /// Underlying ticker, [None] if looping in JavaScript.
+    pub fn ticker(&self) -> Option<&T> {
+        match &self.inner.driver {
+            Driver::Ticker(ticker) => Some(ticker),
+            Driver::Loop { .. } => None,
+        }
+    }
+
+    /// Check before starting the loop, and set [State::Started].
+    fn start_loop(&self) -> Result<(), TickerError> {
+        match self.state() {
+            State::Started => Err(TickerError::AlreadyStarted),
+            State::Stopped => {
+                self.inner.state.set(State::Started);
+                Ok(())
+            }
+            State::Error(e) => Err(e),
+        }
+    }
+}
+
+impl<T: Ticker + Clone + 'static> Clone for FunctionTicker<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Ticker + Clone + 'static> PartialEq for FunctionTicker<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T: Ticker + Clone + 'static> Eq for FunctionTicker<T> {}
+
+impl<T: Ticker + Clone + 'static> Ticker for FunctionTicker<T> {
+    fn state(&self) -> State {
+        match (&self.inner.driver, &*self.inner.error.borrow()) {
+            (Driver::Ticker(_), Some(e)) => State::Error(TickerError::Js(e.clone())),
+            (Driver::Ticker(ticker), None) => ticker.state(),
+            (Driver::Loop { .. }, _) => self.inner.state.get(),
+        }
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        match &self.inner.driver {
+            Driver::Ticker(ticker) => {
+                if let State::Error(e) = self.state() {
+                    return Err(e);
+                }
+                ticker.start()
+            }
+            Driver::Loop { function_loop, .. } => {
+                self.start_loop()?;
+                function_loop.start().map_err(|e| {
+                    let e = TickerError::ScheduleFailed(e);
+                    self.inner.state.set(State::Error(e.clone()));
+                    e
+                })
+            }
+        }
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        match &self.inner.driver {
+            Driver::Ticker(ticker) => {
+                if let State::Error(e) = self.state() {
+                    return Err(e);
+                }
+                ticker.start_immediate()
+            }
+            Driver::Loop { function_loop, .. } => {
+                self.start_loop()?;
+                function_loop.start_immediate();
+                Ok(())
+            }
+        }
+    }
+
+    fn stop(&self) {
+        self.inner.stop()
+    }
+
+    /// Clear error thrown by the function, or of the wrapped ticker.
+    fn reset(&self) {
+        self.inner.error.borrow_mut().take();
+        if let Driver::Ticker(ticker) = &self.inner.driver {
+            ticker.reset();
+        }
+        self.inner.state.reset();
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.inner.state.observe(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let inner = Rc::downgrade(&self.inner);
+        abort::on_abort(&self.inner.state.aborts, signal, move || {
+            if let Some(inner) = inner.upgrade() {
+                inner.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        abort::abort_signal(&self.inner.state)
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        T::spawn(task)
+    }
+}
@@ -1,8 +1,12 @@
-use crate::{NamedTicker, State, Ticker};
+use crate::abort;
+use crate::bindings::{ref_, unref};
+use crate::state::StateCell;
+use crate::{NamedTicker, State, Ticker, TickerError};
+use std::collections::VecDeque;
 use std::rc::Rc;
 use wasm_bindgen::__rt::WasmRefCell;
 use wasm_bindgen::prelude::*;
-use web_sys::{MessageChannel, MessagePort};
+use web_sys::{AbortSignal, MessageChannel, MessagePort};
 
 /// Constructed by [MessageChannelTickerFactory](crate::factory::MessageChannelTickerFactory).
 ///
@@ -11,7 +15,7 @@ use web_sys::{MessageChannel, MessagePort};
 pub struct MessageChannelTicker {
     pub(crate) port1: MessagePort,
     pub(crate) port2: MessagePort,
-    pub(crate) state: Rc<WasmRefCell<State>>,
+    pub(crate) state: Rc<StateCell>,
     pub(crate) cb: Rc<Closure<dyn FnMut()>>,
     pub(crate) cb_err: Rc<Closure<dyn FnMut(JsValue)>>,
 }
@@ -32,61 +36,178 @@ impl Drop for MessageChannelTicker {
     }
 }
 
+impl MessageChannelTicker {
+    /// Keep Node or Bun process alive while the ports listen, the default.
+    /// Does nothing in other runtimes.
+    pub fn ref_(&self) {
+        ref_(&self.port1);
+        ref_(&self.port2);
+    }
+
+    /// Let Node or Bun process exit while the ports listen, even if started.
+    /// Does nothing in other runtimes.
+    pub fn unref(&self) {
+        unref(&self.port1);
+        unref(&self.port2);
+    }
+}
+
 impl Ticker for MessageChannelTicker {
     #[inline]
     fn state(&self) -> State {
         self.state.borrow().clone()
     }
 
-    fn start(&self) -> Result<(), JsValue> {
+    fn start(&self) -> Result<(), TickerError> {
         match *self.state.borrow() {
-            State::Started => return Err("Ticker started".into()),
+            State::Started => return Err(TickerError::AlreadyStarted),
             State::Stopped => (),
             State::Error(ref e) => return Err(e.clone()),
         };
-        *self.state.borrow_mut() = State::Started;
+        self.state.set(State::Started);
         self.port2.post_message(&JsValue::null()).map_err(|e| {
-            *self.state.borrow_mut() = State::Error(e.clone());
+            let e = TickerError::ScheduleFailed(e);
+            self.state.set(State::Error(e.clone()));
             e
         })
     }
 
-    fn start_immediate(&self) -> Result<(), JsValue> {
+    fn start_immediate(&self) -> Result<(), TickerError> {
         match *self.state.borrow() {
-            State::Started => return Err("Ticker started".into()),
+            State::Started => return Err(TickerError::AlreadyStarted),
             State::Stopped => (),
             State::Error(ref e) => return Err(e.clone()),
         };
-        *self.state.borrow_mut() = State::Started;
+        self.state.set(State::Started);
         self.port1
             .onmessage()
             .as_ref()
             .unwrap()
             .call0(&JsValue::null())
-            .map_err(|e| e)
+            .map_err(TickerError::Js)
             .map(|_| ())
     }
 
     fn stop(&self) {
         if self.state.borrow().eq(&State::Started) {
-            *self.state.borrow_mut() = State::Stopped;
+            self.state.set(State::Stopped);
         }
     }
 
-    fn spawn(task: impl FnOnce() + 'static) -> Result<(), JsValue> {
+    fn reset(&self) {
+        self.state.reset();
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.state.observe(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let state = Rc::downgrade(&self.state);
+        abort::on_abort(&self.state.aborts, signal, move || {
+            if let Some(state) = state.upgrade() {
+                state.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        abort::abort_signal(&self.state)
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
         let cb = Closure::once_into_js(task);
         let channel = MessageChannel::new()?;
         let port1 = channel.port1();
         let port2 = channel.port2();
         port1.set_onmessage(Some(cb.unchecked_ref()));
-        port2.post_message(&JsValue::null())?;
-        Ok(())
+        port2
+            .post_message(&JsValue::null())
+            .map_err(TickerError::ScheduleFailed)
     }
 }
 
 impl NamedTicker for MessageChannelTicker {
     fn check() -> bool {
         static mut RET: Option<bool> = None;
-        unsafe { *RET.get_or_insert_with(|| MessageChannel::new().is_ok()) }
+        unsafe {
+            *RET.get_or_insert_with(|| {
+                cfg!(target_family = "wasm") && MessageChannel::new().is_ok()
+            })
+        }
     }
 }
+
+type TaskQueue = VecDeque<Box<dyn FnOnce()>>;
+
+/// A single [MessageChannel] shared by one-shot tasks.
+///
+/// Every posted message pops exactly one queued task, so the channel is
+/// only listened to while tasks are pending. Detaching `onmessage` when idle
+/// also lets NodeJs exit once the queue is drained.
+struct MessageChannelPool {
+    port1: MessagePort,
+    port2: MessagePort,
+    queue: Rc<WasmRefCell<TaskQueue>>,
+    cb: Closure<dyn FnMut()>,
+}
+
+impl MessageChannelPool {
+    fn new() -> Result<Self, TickerError> {
+        let channel = MessageChannel::new()?;
+        let queue = Rc::new(WasmRefCell::new(TaskQueue::new()));
+        let port1 = channel.port1();
+
+        let queue_ = queue.clone();
+        let port1_ = port1.clone();
+        let cb = Closure::new(move || {
+            let task = queue_.borrow_mut().pop_front();
+            if queue_.borrow().is_empty() {
+                port1_.set_onmessage(None);
+            }
+            if let Some(task) = task {
+                task();
+            }
+        });
+
+        Ok(Self {
+            port1,
+            port2: channel.port2(),
+            queue,
+            cb,
+        })
+    }
+
+    fn post(&self, task: Box<dyn FnOnce()>) -> Result<(), TickerError> {
+        let idle = self.queue.borrow().is_empty();
+        self.queue.borrow_mut().push_back(task);
+        if idle {
+            self.port1
+                .set_onmessage(Some(self.cb.as_ref().unchecked_ref()));
+        }
+        self.port2.post_message(&JsValue::null()).map_err(|e| {
+            self.queue.borrow_mut().pop_back();
+            if idle {
+                self.port1.set_onmessage(None);
+            }
+            TickerError::ScheduleFailed(e)
+        })
+    }
+}
+
+thread_local! {
+    static POOL: WasmRefCell<Option<Rc<MessageChannelPool>>> = WasmRefCell::new(None);
+}
+
+/// Like [MessageChannelTicker::spawn], but reuses one pooled [MessageChannel]
+/// instead of creating a new one per call.
+pub(crate) fn spawn_pooled(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+    let pool = POOL.with(|pool| -> Result<_, TickerError> {
+        let mut pool = pool.borrow_mut();
+        if pool.is_none() {
+            *pool = Some(Rc::new(MessageChannelPool::new()?));
+        }
+        Ok(pool.as_ref().unwrap().clone())
+    })?;
+    pool.post(Box::new(task))
+}
@@ -0,0 +1,145 @@
+use crate::abort;
+use crate::mock::{enqueue, generation, next_id, record, MockSource, Queued};
+use crate::state::StateCell;
+use crate::{NamedTicker, State, Ticker, TickerError};
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::__rt::WasmRefCell;
+use web_sys::AbortSignal;
+
+pub(crate) struct MockInner {
+    id: u64,
+    state: StateCell,
+    /// Generation of the virtual clock a tick is queued in, if any.
+    queued: Cell<Option<u64>>,
+    task: WasmRefCell<Box<dyn FnMut()>>,
+}
+
+impl MockInner {
+    pub(crate) fn tick(self: Rc<Self>) {
+        self.queued.set(None);
+        if self.state.borrow().ne(&State::Started) {
+            return;
+        }
+        record(MockSource::Ticker(self.id));
+        (self.task.borrow_mut())();
+        // Task may have stopped the ticker
+        if self.state.borrow().eq(&State::Started) {
+            self.queue();
+        }
+    }
+
+    fn queue(self: &Rc<Self>) {
+        let generation = generation();
+        if self.queued.replace(Some(generation)) != Some(generation) {
+            enqueue(Queued::Tick(Rc::downgrade(self)));
+        }
+    }
+}
+
+/// Constructed by [MockTickerFactory](crate::factory::MockTickerFactory).
+///
+/// Ticks only when the virtual clock in [mock](crate::mock) advances,
+/// doesn't require a JavaScript runtime.
+#[derive(Clone)]
+pub struct MockTicker {
+    pub(crate) inner: Rc<MockInner>,
+}
+
+impl MockTicker {
+    pub(crate) fn new(task: Box<dyn FnMut()>) -> Self {
+        Self {
+            inner: Rc::new(MockInner {
+                id: next_id(),
+                state: StateCell::new(),
+                queued: Cell::new(None),
+                task: WasmRefCell::new(task),
+            }),
+        }
+    }
+
+    /// Identifier in [MockSource::Ticker] of recorded [schedule](crate::mock::schedule).
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+}
+
+impl PartialEq for MockTicker {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for MockTicker {}
+
+impl Ticker for MockTicker {
+    fn state(&self) -> State {
+        self.inner.state.borrow().clone()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        match *self.inner.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.inner.state.set(State::Started);
+        self.inner.queue();
+        Ok(())
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        match *self.inner.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.inner.state.set(State::Started);
+        record(MockSource::Ticker(self.inner.id));
+        (self.inner.task.borrow_mut())();
+        if self.inner.state.borrow().eq(&State::Started) {
+            self.inner.queue();
+        }
+        Ok(())
+    }
+
+    fn stop(&self) {
+        if self.inner.state.borrow().eq(&State::Started) {
+            self.inner.state.set(State::Stopped);
+        }
+    }
+
+    fn reset(&self) {
+        self.inner.state.reset();
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.inner.state.observe(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let inner = Rc::downgrade(&self.inner);
+        abort::on_abort(&self.inner.state.aborts, signal, move || {
+            if let Some(inner) = inner.upgrade() {
+                inner.state.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        abort::abort_signal(&self.inner.state)
+    }
+
+    /// Queue task to next round of virtual event loop.
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        enqueue(Queued::Spawn(Box::new(task)));
+        Ok(())
+    }
+}
+
+impl NamedTicker for MockTicker {
+    /// Always available.
+    fn check() -> bool {
+        true
+    }
+}
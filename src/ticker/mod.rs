@@ -1,9 +1,35 @@
 mod auto;
+mod backend;
+mod boxed;
+mod function;
 mod message_channel;
+mod mock;
+#[cfg(not(target_family = "wasm"))]
+mod native;
+mod retry;
 mod timers;
+mod wait_async;
 
+pub(crate) use message_channel::spawn_pooled;
 pub use message_channel::MessageChannelTicker;
 
-pub use timers::{AnimationFrameTicker, ImmediateTicker, TimeoutTicker};
+pub use timers::{
+    AnimationFrameTicker, FramePacing, ImmediateTicker, TimeoutTicker, UnrefImmediateTicker,
+    UnrefTimeoutTicker,
+};
+
+pub use wait_async::WaitAsyncTicker;
+pub(crate) use wait_async::{Waiter, UNAVAILABLE as WAIT_ASYNC_UNAVAILABLE};
 
 pub use auto::AutoTicker;
+pub use backend::BackendTicker;
+pub use boxed::BoxedTicker;
+pub(crate) use function::scheduler;
+pub use function::FunctionTicker;
+pub use retry::{RetryPolicy, RetryTicker};
+
+pub(crate) use mock::MockInner;
+pub use mock::MockTicker;
+
+#[cfg(not(target_family = "wasm"))]
+pub use native::NativeTicker;
@@ -0,0 +1,148 @@
+use crate::abort;
+use crate::native::enqueue;
+use crate::state::StateCell;
+use crate::{NamedTicker, State, Ticker, TickerError};
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+use wasm_bindgen::__rt::WasmRefCell;
+use web_sys::AbortSignal;
+
+pub(crate) struct NativeInner {
+    state: StateCell,
+    /// Whether a tick is queued in event loop.
+    queued: Cell<bool>,
+    task: WasmRefCell<Box<dyn FnMut()>>,
+}
+
+impl NativeInner {
+    fn tick(self: Rc<Self>) {
+        self.queued.set(false);
+        if self.state.borrow().ne(&State::Started) {
+            return;
+        }
+        self.call();
+        // Task may have stopped the ticker
+        if self.state.borrow().eq(&State::Started) {
+            self.queue();
+        }
+    }
+
+    fn call(&self) {
+        let result = catch_unwind(AssertUnwindSafe(&mut *self.task.borrow_mut()));
+        if result.is_err() {
+            self.state.set(State::Error(TickerError::CallbackPanicked));
+        }
+    }
+
+    fn queue(self: &Rc<Self>) {
+        if !self.queued.replace(true) {
+            let weak = Rc::downgrade(self);
+            enqueue(Box::new(move || {
+                if let Some(inner) = weak.upgrade() {
+                    inner.tick();
+                }
+            }));
+        }
+    }
+}
+
+/// Constructed by [NativeTickerFactory](crate::factory::NativeTickerFactory).
+///
+/// Available on native targets, ticks while [native](crate::native) event loop runs.
+///
+/// A panicking task stops the ticker with [TickerError::CallbackPanicked].
+#[derive(Clone)]
+pub struct NativeTicker {
+    pub(crate) inner: Rc<NativeInner>,
+}
+
+impl NativeTicker {
+    pub(crate) fn new(task: Box<dyn FnMut()>) -> Self {
+        Self {
+            inner: Rc::new(NativeInner {
+                state: StateCell::new(),
+                queued: Cell::new(false),
+                task: WasmRefCell::new(task),
+            }),
+        }
+    }
+}
+
+impl PartialEq for NativeTicker {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for NativeTicker {}
+
+impl Ticker for NativeTicker {
+    fn state(&self) -> State {
+        self.inner.state.borrow().clone()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        match *self.inner.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.inner.state.set(State::Started);
+        self.inner.queue();
+        Ok(())
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        match *self.inner.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.inner.state.set(State::Started);
+        self.inner.call();
+        if self.inner.state.borrow().eq(&State::Started) {
+            self.inner.queue();
+        }
+        Ok(())
+    }
+
+    fn stop(&self) {
+        if self.inner.state.borrow().eq(&State::Started) {
+            self.inner.state.set(State::Stopped);
+        }
+    }
+
+    fn reset(&self) {
+        self.inner.state.reset();
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.inner.state.observe(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let inner = Rc::downgrade(&self.inner);
+        abort::on_abort(&self.inner.state.aborts, signal, move || {
+            if let Some(inner) = inner.upgrade() {
+                inner.state.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        abort::abort_signal(&self.inner.state)
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        enqueue(Box::new(task));
+        Ok(())
+    }
+}
+
+impl NamedTicker for NativeTicker {
+    /// Always available on native targets.
+    fn check() -> bool {
+        true
+    }
+}
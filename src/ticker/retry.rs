@@ -0,0 +1,240 @@
+use crate::abort::{self, AbortListeners};
+use crate::bindings::{
+    __wasm_ticker_binding_clear_timeout as clearTimeout,
+    __wasm_ticker_binding_set_timeout_with_delay as setTimeout, require_js, TimeoutToken,
+};
+use crate::{State, Ticker, TickerError};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+use web_sys::AbortSignal;
+
+/// When [RetryTicker] restarts its ticker after scheduling failed.
+///
+/// Delays grow exponentially from `initial_delay` by `multiplier`, up to `max_delay`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RetryPolicy {
+    /// Retries since last [Ticker::start] or [Ticker::reset], [None] for unlimited.
+    pub max_retries: Option<u32>,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    /// 5 retries, from 100ms doubling up to 10s.
+    fn default() -> Self {
+        Self {
+            max_retries: Some(5),
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `attempt` counting from zero, [None] if exhausted.
+    ///
+    /// Clamped to `0..=max_delay`, zero if not a number.
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        if matches!(self.max_retries, Some(max) if attempt >= max) {
+            return None;
+        }
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let max_delay = self.max_delay.as_secs_f64();
+        // Negative or NaN multipliers must not panic `Duration::from_secs_f64`
+        let delay = match delay.is_nan() {
+            true => 0.0,
+            false => delay.clamp(0.0, max_delay),
+        };
+        Some(Duration::from_secs_f64(delay))
+    }
+}
+
+struct Retry<T> {
+    ticker: T,
+    policy: Cell<RetryPolicy>,
+    attempts: Cell<u32>,
+    timer: WasmRefCell<Option<TimeoutToken>>,
+    /// Created on first retry, so native targets never construct it.
+    cb: WasmRefCell<Option<Closure<dyn FnMut()>>>,
+    aborts: AbortListeners,
+}
+
+impl<T: Ticker + 'static> Retry<T> {
+    fn schedule(self: &Rc<Self>) {
+        let attempt = self.attempts.get();
+        let delay = match self.policy.get().delay(attempt) {
+            Some(delay) => delay,
+            None => return,
+        };
+        // Stays in error without timers on native targets
+        if require_js("setTimeout").is_err() {
+            return;
+        }
+        let mut cb = self.cb.borrow_mut();
+        let cb = cb.get_or_insert_with(|| {
+            let this = Rc::downgrade(self);
+            Closure::new(move || {
+                if let Some(retry) = this.upgrade() {
+                    retry.retry();
+                }
+            })
+        });
+        // Stays in error if even the timer fails
+        if let Ok(token) = setTimeout(cb.as_ref().unchecked_ref(), delay.as_secs_f64() * 1000.0) {
+            self.attempts.set(attempt + 1);
+            self.cancel();
+            self.timer.borrow_mut().replace(token);
+        }
+    }
+
+    fn retry(&self) {
+        self.timer.borrow_mut().take();
+        self.ticker.reset();
+        // Failure is observed and scheduled again
+        let _ = self.ticker.start();
+    }
+
+    /// Cancel pending retry and clear error of the ticker, so it starts afresh.
+    fn reset(&self) {
+        self.cancel();
+        self.attempts.set(0);
+        self.ticker.reset();
+    }
+
+    fn cancel(&self) {
+        if let Some(token) = self.timer.borrow_mut().take() {
+            clearTimeout(token);
+        }
+    }
+}
+
+impl<T> Drop for Retry<T> {
+    fn drop(&mut self) {
+        if let Some(token) = self.timer.borrow_mut().take() {
+            clearTimeout(token);
+        }
+    }
+}
+
+/// Wraps a [Ticker], resetting and restarting it by [RetryPolicy]
+/// after [TickerError::ScheduleFailed], waiting by `setTimeout`.
+///
+/// [Ticker::state] is [State::Error] while waiting for a retry,
+/// observers registered by [Ticker::on_state_change] are notified of each attempt.
+/// Without `setTimeout` on native targets, the ticker stays in [State::Error] instead.
+///
+/// Failures are observed by [Ticker::on_state_change] of the wrapped ticker,
+/// so tickers keeping its default implementation are never retried.
+pub struct RetryTicker<T: Ticker + 'static> {
+    inner: Rc<Retry<T>>,
+}
+
+impl<T: Ticker + 'static> RetryTicker<T> {
+    pub fn new(ticker: T, policy: RetryPolicy) -> Self {
+        let inner = Rc::new(Retry {
+            ticker,
+            policy: Cell::new(policy),
+            attempts: Cell::new(0),
+            timer: WasmRefCell::new(None),
+            cb: WasmRefCell::new(None),
+            aborts: AbortListeners::default(),
+        });
+        let this = Rc::downgrade(&inner);
+        inner.ticker.on_state_change(Box::new(move |state| {
+            if let State::Error(TickerError::ScheduleFailed(_)) = state {
+                if let Some(retry) = this.upgrade() {
+                    retry.schedule();
+                }
+            }
+        }));
+        Self { inner }
+    }
+
+    pub fn ticker(&self) -> &T {
+        &self.inner.ticker
+    }
+
+    pub fn policy(&self) -> RetryPolicy {
+        self.inner.policy.get()
+    }
+
+    pub fn set_policy(&self, policy: RetryPolicy) {
+        self.inner.policy.set(policy);
+    }
+
+    /// Retries scheduled since last [Ticker::start] or [Ticker::reset].
+    pub fn attempts(&self) -> u32 {
+        self.inner.attempts.get()
+    }
+}
+
+impl<T: Ticker + 'static> Clone for RetryTicker<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Ticker + 'static> PartialEq for RetryTicker<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T: Ticker + 'static> Eq for RetryTicker<T> {}
+
+impl<T: Ticker + 'static> Ticker for RetryTicker<T> {
+    fn state(&self) -> State {
+        self.inner.ticker.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        if self.state() != State::Started {
+            self.inner.reset();
+        }
+        self.inner.ticker.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        if self.state() != State::Started {
+            self.inner.reset();
+        }
+        self.inner.ticker.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.inner.cancel();
+        self.inner.ticker.stop()
+    }
+
+    fn reset(&self) {
+        self.inner.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.inner.ticker.on_state_change(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let inner = Rc::downgrade(&self.inner);
+        abort::on_abort(&self.inner.aborts, signal, move || {
+            if let Some(inner) = inner.upgrade() {
+                RetryTicker { inner }.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        self.inner.ticker.abort_signal()
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        T::spawn(task)
+    }
+}
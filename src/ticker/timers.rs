@@ -1,151 +1,204 @@
-use crate::{NamedTicker, State, Ticker, TimerTicker};
+use super::BackendTicker;
+use crate::backend::{RequestAnimationFrame, SetImmediate, SetTimeout, Unref};
+use crate::bindings::AnimationFrameToken;
+use crate::{NamedTicker, State, Ticker, TickerError, TimerTicker};
 use std::rc::Rc;
 use wasm_bindgen::__rt::WasmRefCell;
-use wasm_bindgen::prelude::*;
-
-macro_rules! timer_ticker_impl {
-    {
-        #[$($Attrs:tt)*]
-        Ticker = $T:ident,
-        Token = $TToken:ty,
-        Check = $FCheck:path,
-        Timer = $FTimer:path,
-        Canceller = $FClear:path,
-    } => {
-        #[derive(Clone)]
-        #[$($Attrs)*]
-        pub struct $T {
-            pub(crate) token: Rc<WasmRefCell<Option<$TToken>>>,
-            pub(crate) state: Rc<WasmRefCell<State>>,
-            pub(crate) cb: Rc<Closure<dyn FnMut()>>,
-        }
+use web_sys::AbortSignal;
 
-        impl PartialEq for $T {
-            fn eq(&self, other: &Self) -> bool {
-                Rc::ptr_eq(&self.state, &other.state)
-            }
-        }
+/// Constructed by [TimeoutTickerFactory](crate::factory::TimeoutTickerFactory).
+pub type TimeoutTicker = BackendTicker<SetTimeout>;
 
-        impl Eq for $T {}
+/// Constructed by [ImmediateTickerFactory](crate::factory::ImmediateTickerFactory).
+/// Available in Node and Bun.
+pub type ImmediateTicker = BackendTicker<SetImmediate>;
 
-        impl Drop for $T {
-            fn drop(&mut self) {
-                if let Some(token) = self.token.borrow_mut().take() {
-                    $FClear(token)
-                }
-            }
-        }
+/// Constructed by [UnrefTimeoutTickerFactory](crate::factory::UnrefTimeoutTickerFactory),
+/// not keeping the process alive, see [Unref].
+pub type UnrefTimeoutTicker = BackendTicker<Unref<SetTimeout>>;
 
-        impl Ticker for $T {
-            fn state(&self) -> State {
-                self.state.borrow().clone()
-            }
+/// Constructed by [UnrefImmediateTickerFactory](crate::factory::UnrefImmediateTickerFactory),
+/// not keeping the process alive, see [Unref]. Available in Node and Bun.
+pub type UnrefImmediateTicker = BackendTicker<Unref<SetImmediate>>;
 
-            fn start(&self) -> Result<(), JsValue> {
-                let state = &mut *self.state.borrow_mut();
-                match state {
-                    State::Started => Err("Ticker started".into()),
-                    State::Stopped => {
-                        *state = State::Started;
-                        self.token.borrow_mut()
-                            .replace($FTimer(self.cb.as_ref().as_ref().unchecked_ref())?);
-                        Ok(())
-                    },
-                    State::Error(e) => Err(e.clone()),
-                }
-            }
+/// Constructed by [AnimationFrameTickerFactory](crate::factory::AnimationFrameTickerFactory).
+/// Available in browser Window context.
+#[derive(Clone)]
+pub struct AnimationFrameTicker {
+    pub(crate) inner: BackendTicker<RequestAnimationFrame>,
+    pub(crate) pacing: Rc<WasmRefCell<FramePacing>>,
+}
 
-            fn start_immediate(&self) -> Result<(), JsValue> {
-                let mut state = self.state.borrow_mut();
-                match &*state {
-                    State::Started => Err("Ticker started".into()),
-                    State::Stopped => {
-                        *state = State::Started;
-                        drop(state);
-                        self.cb.as_ref().as_ref()
-                            .unchecked_ref::<js_sys::Function>()
-                            .call0(&JsValue::null())?;
-                        Ok(())
-                    },
-                    State::Error(e) => Err(e.clone()),
-                }
-            }
+impl PartialEq for AnimationFrameTicker {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
 
-            fn stop(&self) {
-                if self.state.borrow().eq(&State::Started) {
-                    if let Some(token) = self.token.borrow_mut().take() {
-                        $FClear(token)
-                    }
-                    *self.state.borrow_mut() = State::Stopped;
-                }
-            }
+impl Eq for AnimationFrameTicker {}
+
+impl Ticker for AnimationFrameTicker {
+    fn state(&self) -> State {
+        self.inner.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        self.inner.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        self.inner.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.inner.stop()
+    }
+
+    fn reset(&self) {
+        self.inner.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.inner.on_state_change(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        self.inner.stop_on_abort(signal)
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        self.inner.abort_signal()
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        BackendTicker::<RequestAnimationFrame>::spawn(task)
+    }
+}
 
-            fn spawn(task: impl FnOnce() + 'static) -> Result<(), JsValue> {
-                let cb = Closure::once_into_js(task);
-                $FTimer(cb.unchecked_ref())?;
-                Ok(())
+impl NamedTicker for AnimationFrameTicker {
+    fn check() -> bool {
+        BackendTicker::<RequestAnimationFrame>::check()
+    }
+}
+
+impl TimerTicker for AnimationFrameTicker {
+    type Token = AnimationFrameToken;
+
+    fn token(&self) -> Option<Self::Token> {
+        self.inner.token()
+    }
+}
+
+/// Smoothing factor of measured frame intervals.
+const EMA_ALPHA: f64 = 0.1;
+
+/// Frame intervals longer than this, e.g. the ticker was stopped
+/// or the page was hidden, are not measured.
+const MAX_MEASURED_INTERVAL: f64 = 1000.0;
+
+fn ema(average: Option<f64>, value: f64) -> f64 {
+    match average {
+        Some(average) => average + (value - average) * EMA_ALPHA,
+        None => value,
+    }
+}
+
+/// Frame skipping and rate measurement of [AnimationFrameTicker],
+/// timestamps are in milliseconds.
+///
+/// Paces any ticker given frame timestamps, e.g. virtual time of [mock](crate::mock).
+#[derive(Default)]
+pub struct FramePacing {
+    target_interval: Option<f64>,
+    next_tick: Option<f64>,
+    last_frame: Option<f64>,
+    last_tick: Option<f64>,
+    frame_interval: Option<f64>,
+    tick_interval: Option<f64>,
+}
+
+impl FramePacing {
+    pub fn new(target_fps: Option<f64>) -> Self {
+        let mut pacing = Self::default();
+        pacing.set_target_fps(target_fps);
+        pacing
+    }
+
+    /// See [AnimationFrameTicker::set_target_fps].
+    pub fn set_target_fps(&mut self, target_fps: Option<f64>) {
+        self.target_interval = target_fps.filter(|fps| *fps > 0.0).map(|fps| 1000.0 / fps);
+        self.next_tick = None;
+    }
+
+    /// Record a frame, returning whether task should be called on it.
+    pub fn frame(&mut self, timestamp: f64) -> bool {
+        if let Some(last) = self.last_frame.replace(timestamp) {
+            let interval = timestamp - last;
+            if interval > 0.0 && interval < MAX_MEASURED_INTERVAL {
+                self.frame_interval = Some(ema(self.frame_interval, interval));
             }
         }
 
-        impl NamedTicker for $T {
-            fn check() -> bool {
-                $FCheck()
+        if let (Some(target), Some(next)) = (self.target_interval, self.next_tick) {
+            // Frames never land exactly on schedule, accept the nearest one
+            let tolerance = self.frame_interval.unwrap_or(0.0) / 2.0;
+            if timestamp < next - tolerance {
+                return false;
             }
+            let next = next + target;
+            self.next_tick = Some(if next <= timestamp {
+                timestamp + target
+            } else {
+                next
+            });
+        } else if let Some(target) = self.target_interval {
+            self.next_tick = Some(timestamp + target);
         }
 
-        impl TimerTicker for $T {
-            type Token = $TToken;
-
-            fn token(&self) -> Option<Self::Token> {
-                self.token.borrow().clone()
+        if let Some(last) = self.last_tick.replace(timestamp) {
+            let interval = timestamp - last;
+            if interval > 0.0 && interval < MAX_MEASURED_INTERVAL {
+                self.tick_interval = Some(ema(self.tick_interval, interval));
             }
         }
-    };
-}
+        true
+    }
 
-use crate::bindings::{
-    __wasm_ticker_binding_clear_timeout as clearTimeout,
-    __wasm_ticker_binding_set_timeout as setTimeout,
-    has_set_timeout,
-    TimeoutToken
-};
-timer_ticker_impl! {
-    #[doc = "Constructed by [TimeoutTickerFactory](crate::factory::TimeoutTickerFactory)."]
-    Ticker = TimeoutTicker,
-    Token = TimeoutToken,
-    Check = has_set_timeout,
-    Timer = setTimeout,
-    Canceller = clearTimeout,
-}
+    /// Target ticks per second, [None] if ticking on every frame.
+    pub fn target_fps(&self) -> Option<f64> {
+        self.target_interval.map(|interval| 1000.0 / interval)
+    }
+
+    /// Measured ticks per second, [None] before second tick.
+    pub fn fps(&self) -> Option<f64> {
+        self.tick_interval.map(|interval| 1000.0 / interval)
+    }
 
-use crate::bindings::{
-    __wasm_ticker_binding_clear_immediate as clearImmediate,
-    __wasm_ticker_binding_set_immediate as setImmediate,
-    has_set_immediate,
-    ImmediateToken
-};
-timer_ticker_impl! {
-    #[doc = "Constructed by [ImmediateTickerFactory](crate::factory::ImmediateTickerFactory).\
-    Available in NodeJs."]
-    Ticker = ImmediateTicker,
-    Token = ImmediateToken,
-    Check = has_set_immediate,
-    Timer = setImmediate,
-    Canceller = clearImmediate,
+    /// Estimated display refresh rate in Hz, [None] before second frame.
+    pub fn refresh_rate(&self) -> Option<f64> {
+        self.frame_interval.map(|interval| 1000.0 / interval)
+    }
 }
 
-use crate::bindings::{
-    __wasm_ticker_binding_cancel_animation_frame as cancelAnimationFrame,
-    __wasm_ticker_binding_request_animation_frame as requestAnimationFrame,
-    has_request_animation_frame,
-    AnimationFrameToken
-};
-timer_ticker_impl! {
-    #[doc = "Constructed by [AnimationFrameTickerFactory](crate::factory::AnimationFrameTickerFactory).\
-    Available in browser Window context."]
-    Ticker = AnimationFrameTicker,
-    Token = AnimationFrameToken,
-    Check = has_request_animation_frame,
-    Timer = requestAnimationFrame,
-    Canceller = cancelAnimationFrame,
+impl AnimationFrameTicker {
+    /// Target ticks per second, [None] if ticking on every frame.
+    pub fn target_fps(&self) -> Option<f64> {
+        self.pacing.borrow().target_fps()
+    }
+
+    /// Skip frames to tick at most `target_fps` times per second,
+    /// or tick on every frame if [None].
+    pub fn set_target_fps(&self, target_fps: Option<f64>) {
+        self.pacing.borrow_mut().set_target_fps(target_fps);
+    }
+
+    /// Measured ticks per second, [None] before second tick.
+    pub fn fps(&self) -> Option<f64> {
+        self.pacing.borrow().fps()
+    }
+
+    /// Estimated display refresh rate in Hz, [None] before second frame.
+    pub fn refresh_rate(&self) -> Option<f64> {
+        self.pacing.borrow().refresh_rate()
+    }
 }
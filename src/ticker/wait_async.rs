@@ -0,0 +1,243 @@
+use crate::abort;
+use crate::bindings::{
+    has_wait_async, memory_i32_index, memory_i32_view, promise_then, wait_async_promise,
+};
+use crate::state::StateCell;
+use crate::ticker::AutoTicker;
+use crate::{NamedTicker, State, Ticker, TickerError};
+use js_sys::{Atomics, Int32Array, SharedArrayBuffer};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Arc;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+use web_sys::AbortSignal;
+
+pub(crate) const UNAVAILABLE: &str = "SharedArrayBuffer and Atomics.waitAsync";
+
+pub(crate) struct Waiter {
+    pub(crate) array: Int32Array,
+    pub(crate) index: u32,
+    pub(crate) state: StateCell,
+    /// Whether a `waitAsync` promise is pending, which can't be cancelled.
+    pub(crate) waiting: Cell<bool>,
+    /// Taken while called, so the task may start or stop the ticker.
+    pub(crate) task: WasmRefCell<Option<Box<dyn FnMut()>>>,
+    /// Keeps slot in WebAssembly memory alive.
+    pub(crate) _atomic: Option<Arc<AtomicI32>>,
+}
+
+impl Waiter {
+    fn load(&self) -> Result<i32, JsValue> {
+        Atomics::load(&self.array, self.index)
+    }
+
+    /// Call the task, skipped if called from within the task.
+    fn call(&self) {
+        let task = self.task.borrow_mut().take();
+        if let Some(mut task) = task {
+            task();
+            self.task.borrow_mut().replace(task);
+        }
+    }
+
+    /// Wait until the slot differs from `value`.
+    ///
+    /// If it already does, wake in a task of [AutoTicker::spawn] instead of a microtask,
+    /// so notifications during each tick don't starve the event loop.
+    fn wait(self: &Rc<Self>, value: i32) -> Result<(), TickerError> {
+        if self.waiting.get() {
+            return Ok(());
+        }
+        let result = Atomics::wait_async(&self.array, self.index, value)
+            .map_err(TickerError::ScheduleFailed)?;
+        let promise = wait_async_promise(&result).map_err(TickerError::ScheduleFailed)?;
+        let weak = Rc::downgrade(self);
+        let wake = move || {
+            if let Some(waiter) = weak.upgrade() {
+                waiter.wake();
+            }
+        };
+        match promise {
+            Some(promise) => {
+                promise_then(&promise, &Closure::once_into_js(move |_: JsValue| wake()))
+                    .map_err(TickerError::ScheduleFailed)?
+            }
+            None => AutoTicker::spawn(wake)?,
+        }
+        self.waiting.set(true);
+        Ok(())
+    }
+
+    fn wake(self: Rc<Self>) {
+        self.waiting.set(false);
+        if self.state.borrow().ne(&State::Started) {
+            return;
+        }
+        // Notifications during the task wake the next wait at once
+        let value = match self.load() {
+            Ok(value) => value,
+            Err(e) => {
+                self.state.set(State::Error(TickerError::ScheduleFailed(e)));
+                return;
+            }
+        };
+        self.call();
+        // Task may have stopped the ticker
+        if self.state.borrow().eq(&State::Started) {
+            if let Err(e) = self.wait(value) {
+                self.state.set(State::Error(e));
+            }
+        }
+    }
+}
+
+/// Constructed by [WaitAsyncTickerFactory](crate::factory::WaitAsyncTickerFactory).
+///
+/// Ticks each time its slot of a shared [Int32Array] is notified
+/// by [`Atomics.notify`][notify], e.g. from a worker, waiting by [`Atomics.waitAsync`][waitAsync].
+/// Notifications arriving before the previous tick are merged.
+///
+/// Requires `SharedArrayBuffer` and `Atomics.waitAsync`,
+/// available in NodeJs and cross-origin isolated browser contexts.
+///
+/// [notify]: https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Atomics/notify
+/// [waitAsync]: https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync
+#[derive(Clone)]
+pub struct WaitAsyncTicker {
+    pub(crate) waiter: Rc<Waiter>,
+}
+
+impl WaitAsyncTicker {
+    /// Shared array containing the slot, which can be posted to workers.
+    pub fn array(&self) -> &Int32Array {
+        &self.waiter.array
+    }
+
+    /// Index of the slot in [WaitAsyncTicker::array].
+    pub fn index(&self) -> u32 {
+        self.waiter.index
+    }
+
+    /// Change the slot and notify waiting ticker, from current thread.
+    pub fn notify(&self) -> Result<(), JsValue> {
+        Atomics::add(&self.waiter.array, self.waiter.index, 1)?;
+        Atomics::notify(&self.waiter.array, self.waiter.index)?;
+        Ok(())
+    }
+
+    /// Change `atomic` and notify ticker waiting on it, from any thread.
+    ///
+    /// See [WaitAsyncTickerFactory::new_with_atomic](crate::factory::WaitAsyncTickerFactory::new_with_atomic).
+    pub fn notify_atomic(atomic: &AtomicI32) -> Result<(), JsValue> {
+        atomic.fetch_add(1, Ordering::SeqCst);
+        Atomics::notify(&memory_i32_view(), memory_i32_index(atomic))?;
+        Ok(())
+    }
+}
+
+impl PartialEq for WaitAsyncTicker {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.waiter, &other.waiter)
+    }
+}
+
+impl Eq for WaitAsyncTicker {}
+
+impl Ticker for WaitAsyncTicker {
+    fn state(&self) -> State {
+        self.waiter.state.borrow().clone()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        match *self.waiter.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.waiter.state.set(State::Started);
+        self.waiter
+            .load()
+            .map_err(TickerError::ScheduleFailed)
+            .and_then(|value| self.waiter.wait(value))
+            .map_err(|e| {
+                self.waiter.state.set(State::Error(e.clone()));
+                e
+            })
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        match *self.waiter.state.borrow() {
+            State::Started => return Err(TickerError::AlreadyStarted),
+            State::Stopped => (),
+            State::Error(ref e) => return Err(e.clone()),
+        };
+        self.waiter.state.set(State::Started);
+        let value = self.waiter.load().map_err(|e| {
+            let e = TickerError::ScheduleFailed(e);
+            self.waiter.state.set(State::Error(e.clone()));
+            e
+        })?;
+        self.waiter.call();
+        if self.waiter.state.borrow().ne(&State::Started) {
+            return Ok(());
+        }
+        self.waiter.wait(value).map_err(|e| {
+            self.waiter.state.set(State::Error(e.clone()));
+            e
+        })
+    }
+
+    fn stop(&self) {
+        if self.waiter.state.borrow().eq(&State::Started) {
+            self.waiter.state.set(State::Stopped);
+        }
+    }
+
+    fn reset(&self) {
+        self.waiter.state.reset();
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.waiter.state.observe(observer)
+    }
+
+    fn stop_on_abort(&self, signal: &AbortSignal) -> Result<(), TickerError> {
+        let waiter = Rc::downgrade(&self.waiter);
+        abort::on_abort(&self.waiter.state.aborts, signal, move || {
+            if let Some(waiter) = waiter.upgrade() {
+                waiter.state.stop();
+            }
+        })
+    }
+
+    fn abort_signal(&self) -> Result<AbortSignal, TickerError> {
+        abort::abort_signal(&self.waiter.state)
+    }
+
+    /// Queue task on a new slot and notify it at once.
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        if !Self::check() {
+            return Err(TickerError::Unavailable(UNAVAILABLE));
+        }
+        let array = Int32Array::new(&SharedArrayBuffer::new(4));
+        let result = Atomics::wait_async(&array, 0, 0)?;
+        match wait_async_promise(&result)? {
+            Some(promise) => {
+                promise_then(&promise, &Closure::once_into_js(move |_: JsValue| task()))
+                    .map_err(TickerError::ScheduleFailed)?
+            }
+            // Resolved synchronously, not expected for a new slot
+            None => return AutoTicker::spawn(task),
+        }
+        Atomics::notify(&array, 0)?;
+        Ok(())
+    }
+}
+
+impl NamedTicker for WaitAsyncTicker {
+    fn check() -> bool {
+        has_wait_async()
+    }
+}
@@ -0,0 +1,86 @@
+use crate::bindings::now;
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::time::Duration;
+
+/// A measurement of monotonic clock `performance.now()`,
+/// or [std::time::Instant] on native targets.
+///
+/// [std::time::Instant] is not available on `wasm32-unknown-unknown`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Instant(f64);
+
+impl Instant {
+    pub fn now() -> Self {
+        Self(now())
+    }
+
+    /// Duration elapsed from `earlier`, or zero if `earlier` is later than [Self].
+    pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+        Duration::from_secs_f64((self.0 - earlier.0).max(0.0) / 1000.0)
+    }
+
+    /// Same as [Instant::saturating_duration_since].
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.saturating_duration_since(earlier)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Self::now().saturating_duration_since(*self)
+    }
+
+    pub(crate) fn millis_until(&self, deadline: Instant) -> f64 {
+        deadline.0 - self.0
+    }
+}
+
+impl Eq for Instant {}
+
+impl PartialOrd for Instant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Instant {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `performance.now()` never returns NaN
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Instant {
+        Instant(self.0 + rhs.as_secs_f64() * 1000.0)
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, rhs: Duration) -> Instant {
+        Instant(self.0 - rhs.as_secs_f64() * 1000.0)
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, rhs: Instant) -> Duration {
+        self.saturating_duration_since(rhs)
+    }
+}
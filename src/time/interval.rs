@@ -0,0 +1,151 @@
+use super::{sleep_until, Instant, Sleep};
+use crate::TickerError;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// Ticks later than this are considered missed, as `setTimeout` is never exact.
+const MISSED_THRESHOLD: Duration = Duration::from_millis(5);
+
+/// How [Interval] behaves when ticks are missed,
+/// e.g. the consumer was busy or the page was in background.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MissedTickBehavior {
+    /// Tick as fast as possible until caught up, keeping original schedule.
+    Burst,
+    /// Schedule following ticks one period after the late tick.
+    Delay,
+    /// Skip missed ticks, continuing on the next multiple of period
+    /// from original schedule.
+    Skip,
+}
+
+impl MissedTickBehavior {
+    fn next_timeout(&self, timeout: Instant, now: Instant, period: Duration) -> Instant {
+        match self {
+            MissedTickBehavior::Burst => timeout + period,
+            MissedTickBehavior::Delay => now + period,
+            MissedTickBehavior::Skip => {
+                let period_ms = period.as_secs_f64() * 1000.0;
+                let late_ms = timeout.millis_until(now) % period_ms;
+                now + Duration::from_secs_f64((period_ms - late_ms) / 1000.0)
+            }
+        }
+    }
+}
+
+impl Default for MissedTickBehavior {
+    fn default() -> Self {
+        MissedTickBehavior::Burst
+    }
+}
+
+/// Periodic ticks created by [interval] and [interval_at], using `setTimeout`.
+///
+/// Same semantics as `tokio::time::Interval`.
+pub struct Interval {
+    sleep: Sleep,
+    period: Duration,
+    missed_tick_behavior: MissedTickBehavior,
+}
+
+impl Interval {
+    /// Wait until next tick, returning its scheduled [Instant].
+    pub fn tick(&mut self) -> Tick<'_> {
+        Tick { interval: self }
+    }
+
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Result<Instant, TickerError>> {
+        match Pin::new(&mut self.sleep).poll(cx) {
+            Poll::Ready(Ok(())) => (),
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        }
+        let timeout = self.sleep.deadline();
+        let now = Instant::now();
+        let next = if now > timeout + MISSED_THRESHOLD {
+            self.missed_tick_behavior
+                .next_timeout(timeout, now, self.period)
+        } else {
+            timeout + self.period
+        };
+        self.sleep.reset(next);
+        Poll::Ready(Ok(timeout))
+    }
+
+    /// Next tick completes after one period from now.
+    pub fn reset(&mut self) {
+        self.sleep.reset(Instant::now() + self.period);
+    }
+
+    /// Next tick completes immediately.
+    pub fn reset_immediately(&mut self) {
+        self.sleep.reset(Instant::now());
+    }
+
+    /// Next tick completes at `deadline`.
+    pub fn reset_at(&mut self, deadline: Instant) {
+        self.sleep.reset(deadline);
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Change period of ticks after next one,
+    /// call [Interval::reset] to apply it to next tick as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn set_period(&mut self, period: Duration) {
+        assert!(period > Duration::ZERO, "`period` must be non-zero.");
+        self.period = period;
+    }
+
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+}
+
+/// Future returned by [Interval::tick].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Tick<'a> {
+    interval: &'a mut Interval,
+}
+
+impl Future for Tick<'_> {
+    type Output = Result<Instant, TickerError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.interval.poll_tick(cx)
+    }
+}
+
+/// Create [Interval] ticking every `period`, first tick completes immediately.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn interval(period: Duration) -> Interval {
+    interval_at(Instant::now(), period)
+}
+
+/// Create [Interval] ticking every `period`, first tick completes at `start`.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn interval_at(start: Instant, period: Duration) -> Interval {
+    assert!(period > Duration::ZERO, "`period` must be non-zero.");
+    Interval {
+        sleep: sleep_until(start),
+        period,
+        missed_tick_behavior: MissedTickBehavior::default(),
+    }
+}
@@ -0,0 +1,12 @@
+mod instant;
+mod interval;
+mod sleep;
+mod timeout;
+
+pub use instant::Instant;
+
+pub use interval::{interval, interval_at, Interval, MissedTickBehavior, Tick};
+
+pub use sleep::{sleep, sleep_until, Sleep};
+
+pub use timeout::{timeout, Timeout, TimeoutError};
@@ -0,0 +1,125 @@
+use super::Instant;
+use crate::bindings::{
+    __wasm_ticker_binding_clear_timeout as clearTimeout,
+    __wasm_ticker_binding_set_timeout_with_delay as setTimeout, require_js, TimeoutToken,
+};
+use crate::TickerError;
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+use wasm_bindgen::prelude::*;
+
+/// Maximum delay accepted by `setTimeout`, longer ones are split.
+const MAX_DELAY: f64 = i32::MAX as f64;
+
+struct Shared {
+    armed: Cell<bool>,
+    waker: WasmRefCell<Option<Waker>>,
+}
+
+/// Future returned by [sleep] and [sleep_until].
+///
+/// Pending timer is cleared when dropped.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Sleep {
+    deadline: Instant,
+    shared: Rc<Shared>,
+    token: Option<TimeoutToken>,
+    cb: Option<Closure<dyn FnMut()>>,
+}
+
+impl Sleep {
+    fn new(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            shared: Rc::new(Shared {
+                armed: Cell::new(false),
+                waker: WasmRefCell::new(None),
+            }),
+            token: None,
+            cb: None,
+        }
+    }
+
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    pub fn is_elapsed(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Reset deadline, this doesn't wake current waiting task.
+    pub fn reset(&mut self, deadline: Instant) {
+        self.cancel();
+        self.deadline = deadline;
+    }
+
+    fn cancel(&mut self) {
+        if let Some(token) = self.token.take() {
+            if self.shared.armed.replace(false) {
+                clearTimeout(token);
+            }
+        }
+    }
+
+    fn arm(&mut self, delay: f64) -> Result<(), TickerError> {
+        require_js("setTimeout")?;
+        let shared = self.shared.clone();
+        let cb = self.cb.get_or_insert_with(|| {
+            Closure::new(move || {
+                shared.armed.set(false);
+                if let Some(waker) = shared.waker.borrow_mut().take() {
+                    waker.wake();
+                }
+            })
+        });
+        let delay = delay.ceil().clamp(0.0, MAX_DELAY);
+        let token = setTimeout(cb.as_ref().unchecked_ref(), delay);
+        self.token = Some(token.map_err(TickerError::ScheduleFailed)?);
+        self.shared.armed.set(true);
+        Ok(())
+    }
+}
+
+impl Future for Sleep {
+    type Output = Result<(), TickerError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let remaining = Instant::now().millis_until(self.deadline);
+        if remaining <= 0.0 {
+            self.cancel();
+            return Poll::Ready(Ok(()));
+        }
+        *self.shared.waker.borrow_mut() = Some(cx.waker().clone());
+        // Timers may fire early or be split, re-arm for the rest
+        if !self.shared.armed.get() {
+            if let Err(e) = self.arm(remaining) {
+                return Poll::Ready(Err(e));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Wait until `duration` has elapsed, using `setTimeout`.
+///
+/// Fails with [TickerError::Unavailable] on native targets.
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep::new(Instant::now() + duration)
+}
+
+/// Wait until `deadline` is reached, using `setTimeout`.
+pub fn sleep_until(deadline: Instant) -> Sleep {
+    Sleep::new(deadline)
+}
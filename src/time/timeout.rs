@@ -0,0 +1,83 @@
+use super::{sleep, Sleep};
+use crate::TickerError;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+
+/// Error returned by [Timeout].
+#[derive(Clone, PartialEq, Debug)]
+pub enum TimeoutError {
+    /// Deadline has elapsed before the future completes.
+    Elapsed,
+    /// Failed to schedule the timer.
+    Timer(TickerError),
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeoutError::Elapsed => f.write_str("deadline has elapsed"),
+            TimeoutError::Timer(e) => write!(f, "failed to schedule timer: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+impl From<TimeoutError> for JsValue {
+    fn from(value: TimeoutError) -> Self {
+        match value {
+            TimeoutError::Elapsed => JsValue::from_str("Deadline has elapsed"),
+            TimeoutError::Timer(e) => e.into(),
+        }
+    }
+}
+
+/// Future returned by [timeout].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Timeout<F> {
+    future: F,
+    sleep: Sleep,
+}
+
+impl<F> Timeout<F> {
+    pub fn get_ref(&self) -> &F {
+        &self.future
+    }
+
+    pub fn into_inner(self) -> F {
+        self.future
+    }
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, TimeoutError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is structurally pinned and never moved out of pinned `self`,
+        // `sleep` is `Unpin`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        if let Poll::Ready(v) = future.poll(cx) {
+            return Poll::Ready(Ok(v));
+        }
+        match Pin::new(&mut this.sleep).poll(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Err(TimeoutError::Elapsed)),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(TimeoutError::Timer(e))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Require `future` to complete within `duration`.
+///
+/// The timer is cleared once [Timeout] is dropped.
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future,
+        sleep: sleep(duration),
+    }
+}
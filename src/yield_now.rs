@@ -0,0 +1,105 @@
+use crate::bindings::now;
+use crate::factory::{SelectedTicker, SELECTED_TICKER};
+use crate::ticker::{spawn_pooled, AutoTicker};
+use crate::{Ticker, TickerError};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use wasm_bindgen::__rt::WasmRefCell;
+
+thread_local! {
+    static LAST_YIELD: Cell<Option<f64>> = Cell::new(None);
+}
+
+struct Shared {
+    fired: Cell<bool>,
+    waker: WasmRefCell<Option<Waker>>,
+}
+
+enum Inner {
+    Init,
+    Pending(Rc<Shared>),
+    Done,
+}
+
+/// Future returned by [yield_now] and [yield_if_needed].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct YieldNow {
+    inner: Inner,
+}
+
+impl Future for YieldNow {
+    type Output = Result<(), TickerError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &self.inner {
+            Inner::Init => {
+                let shared = Rc::new(Shared {
+                    fired: Cell::new(false),
+                    waker: WasmRefCell::new(Some(cx.waker().clone())),
+                });
+                let shared_ = shared.clone();
+                let task = move || {
+                    shared_.fired.set(true);
+                    if let Some(waker) = shared_.waker.borrow_mut().take() {
+                        waker.wake();
+                    }
+                };
+                let res = match *SELECTED_TICKER {
+                    SelectedTicker::MessageChannel => spawn_pooled(task),
+                    _ => AutoTicker::spawn(task),
+                };
+                if let Err(e) = res {
+                    self.inner = Inner::Done;
+                    return Poll::Ready(Err(e));
+                }
+                self.inner = Inner::Pending(shared);
+                Poll::Pending
+            }
+            Inner::Pending(shared) => {
+                if shared.fired.get() {
+                    self.inner = Inner::Done;
+                    LAST_YIELD.with(|last| last.set(Some(now())));
+                    Poll::Ready(Ok(()))
+                } else {
+                    *shared.waker.borrow_mut() = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+            Inner::Done => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+/// Yield to JavaScript event loop, resuming on next [Task].
+///
+/// The wakeup is queued by the backend [AutoTicker] would select,
+/// reusing one pooled [MessageChannel](web_sys::MessageChannel) when available,
+/// so it is cheap enough to be awaited thousands of times per second.
+///
+/// [Task]: https://developer.mozilla.org/docs/Web/API/HTML_DOM_API/Microtask_guide
+pub fn yield_now() -> YieldNow {
+    YieldNow { inner: Inner::Init }
+}
+
+/// [yield_now] only if more than `budget` elapsed since last yield.
+///
+/// The first call only starts measuring and resolves immediately.
+pub fn yield_if_needed(budget: Duration) -> YieldNow {
+    let now = now();
+    let needed = LAST_YIELD.with(|last| match last.get() {
+        Some(last) => now - last >= budget.as_secs_f64() * 1000.0,
+        None => {
+            last.set(Some(now));
+            false
+        }
+    });
+    if needed {
+        yield_now()
+    } else {
+        YieldNow { inner: Inner::Done }
+    }
+}
@@ -0,0 +1,176 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::{AutoTickerFactory, MessageChannelTickerFactory, TimeoutTickerFactory};
+use wasm_ticker::ticker::{AutoTicker, TimeoutTicker};
+use wasm_ticker::time::sleep;
+use wasm_ticker::{State, Ticker, TickerError, TickerFactory};
+use web_sys::AbortController;
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+fn stop_on_abort() -> Result<(), JsValue> {
+    let controller = AbortController::new()?;
+    let ticker = TimeoutTickerFactory::new(|| ())?;
+    ticker.start()?;
+    ticker.stop_on_abort(&controller.signal())?;
+    assert_eq!(ticker.state(), State::Started);
+    controller.abort();
+    assert_eq!(ticker.state(), State::Stopped);
+
+    // Already aborted
+    ticker.start()?;
+    ticker.stop_on_abort(&controller.signal())?;
+    assert_eq!(ticker.state(), State::Stopped);
+    Ok(())
+}
+
+/// Ticker keeping the default [Ticker::stop_on_abort], counting its clones.
+#[derive(Clone, PartialEq, Eq)]
+struct Counted(Rc<()>, TimeoutTicker);
+
+impl Ticker for Counted {
+    fn state(&self) -> State {
+        self.1.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        self.1.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        self.1.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.1.stop()
+    }
+
+    fn reset(&self) {
+        self.1.reset()
+    }
+
+    fn on_state_change(&self, observer: Box<dyn FnMut(&State)>) {
+        self.1.on_state_change(observer)
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        TimeoutTicker::spawn(task)
+    }
+}
+
+#[wasm_bindgen_test]
+fn default_stop_on_abort() -> Result<(), JsValue> {
+    let ticker = Counted(Rc::new(()), TimeoutTickerFactory::new(|| ())?);
+    let controller = AbortController::new()?;
+    ticker.start()?;
+    ticker.stop_on_abort(&controller.signal())?;
+    assert_eq!(Rc::strong_count(&ticker.0), 2);
+    controller.abort();
+    assert_eq!(ticker.state(), State::Stopped);
+    assert_eq!(Rc::strong_count(&ticker.0), 1);
+
+    // Released once stopped without aborting
+    let controller = AbortController::new()?;
+    ticker.start()?;
+    ticker.stop_on_abort(&controller.signal())?;
+    ticker.stop();
+    assert_eq!(Rc::strong_count(&ticker.0), 1);
+    Ok(())
+}
+
+async fn restart_after_abort_impl<F: TickerFactory>() -> Result<(), JsValue>
+where
+    F::Output: 'static,
+{
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = F::new(move || n_.set(n_.get() + 1))?;
+    let controller = AbortController::new()?;
+    ticker.stop_on_abort(&controller.signal())?;
+    ticker.start()?;
+    controller.abort();
+    assert_eq!(ticker.state(), State::Stopped);
+    // Listener released its handle, which must not tear down the ticker
+    sleep(Duration::from_millis(20)).await?;
+
+    let ticks = n.get();
+    ticker.start()?;
+    sleep(Duration::from_millis(20)).await?;
+    ticker.stop();
+    assert!(n.get() > ticks);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn restart_after_abort() -> Result<(), JsValue> {
+    restart_after_abort_impl::<AutoTickerFactory>().await?;
+    restart_after_abort_impl::<MessageChannelTickerFactory>().await?;
+    restart_after_abort_impl::<TimeoutTickerFactory>().await
+}
+
+#[wasm_bindgen_test]
+fn abort_signal() -> Result<(), JsValue> {
+    let ticker = TimeoutTickerFactory::new(|| ())?;
+    let signal = ticker.abort_signal()?;
+    ticker.start()?;
+    assert!(!signal.aborted());
+    ticker.stop();
+    assert!(signal.aborted());
+
+    let error = js_sys::Function::new_no_args("throw new Error('failed')");
+    let ticker =
+        TimeoutTickerFactory::new_with_function(error, JsValue::NULL, js_sys::Array::new())?;
+    let signal = ticker.abort_signal()?;
+    assert!(ticker.start_immediate().is_ok());
+    assert!(signal.aborted());
+    assert!(signal.reason().is_instance_of::<js_sys::Error>());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn spawn_with_signal() -> Result<(), JsValue> {
+    let n = Rc::new(Cell::new(0u32));
+    let controller = AbortController::new()?;
+    let n_ = n.clone();
+    TimeoutTicker::spawn_with_signal(move || n_.set(n_.get() + 1), &controller.signal())?;
+    let n_ = n.clone();
+    AutoTicker::spawn_with_signal(move || n_.set(n_.get() + 1), &controller.signal())?;
+    controller.abort();
+    sleep(Duration::from_millis(20)).await?;
+    assert_eq!(n.get(), 0);
+
+    let n_ = n.clone();
+    let result =
+        TimeoutTicker::spawn_with_signal(move || n_.set(n_.get() + 1), &controller.signal());
+    assert_eq!(result, Err(TickerError::Cancelled));
+
+    let controller = AbortController::new()?;
+    let n_ = n.clone();
+    TimeoutTicker::spawn_with_signal(move || n_.set(n_.get() + 1), &controller.signal())?;
+    sleep(Duration::from_millis(20)).await?;
+    assert_eq!(n.get(), 1);
+    // Listener was removed, aborting later does nothing
+    controller.abort();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn spawn_promise_with_signal() -> Result<(), JsValue> {
+    let controller = AbortController::new()?;
+    let promise = TimeoutTicker::spawn_promise_with_signal(|| Ok(1.into()), &controller.signal())?;
+    assert_eq!(JsFuture::from(promise).await?, 1);
+
+    let promise = TimeoutTicker::spawn_promise_with_signal(|| Ok(2.into()), &controller.signal())?;
+    controller.abort_with_reason(&"aborted".into());
+    assert_eq!(JsFuture::from(promise).await, Err("aborted".into()));
+
+    // Already aborted
+    let promise = TimeoutTicker::spawn_promise_with_signal(|| Ok(3.into()), &controller.signal())?;
+    assert_eq!(JsFuture::from(promise).await, Err("aborted".into()));
+    Ok(())
+}
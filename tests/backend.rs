@@ -0,0 +1,69 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::BackendTickerFactory;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{NamedTicker, ScheduleBackend, State, Ticker, TickerFactory, TimerTicker};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_name = setTimeout)]
+    fn set_timeout(cb: &js_sys::Function) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(token: JsValue);
+}
+
+thread_local! {
+    static SCHEDULED: Cell<u32> = Cell::new(0);
+    static CANCELLED: Cell<u32> = Cell::new(0);
+}
+
+/// Counting wrapper of `setTimeout`.
+struct CountingBackend;
+
+impl ScheduleBackend for CountingBackend {
+    type Token = JsValue;
+
+    fn available() -> bool {
+        true
+    }
+
+    fn schedule(cb: &js_sys::Function) -> Result<JsValue, JsValue> {
+        SCHEDULED.with(|n| n.set(n.get() + 1));
+        set_timeout(cb)
+    }
+
+    fn cancel(token: JsValue) {
+        CANCELLED.with(|n| n.set(n.get() + 1));
+        clear_timeout(token)
+    }
+}
+
+#[wasm_bindgen_test]
+async fn custom_backend() -> Result<(), JsValue> {
+    type Factory = BackendTickerFactory<CountingBackend>;
+    assert!(<Factory as TickerFactory>::Output::check());
+
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = Factory::new(move || n_.set(n_.get() + 1))?;
+    assert!(ticker.token().is_none());
+    ticker.start()?;
+    sleep(Duration::from_millis(100)).await?;
+    ticker.stop();
+    assert_eq!(ticker.state(), State::Stopped);
+    assert!(ticker.token().is_none());
+
+    let ticks = n.get();
+    assert!(ticks > 0);
+    // Initial schedule and one after each tick
+    assert_eq!(SCHEDULED.with(Cell::get), ticks + 1);
+    assert_eq!(CANCELLED.with(Cell::get), 1);
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(n.get(), ticks);
+    Ok(())
+}
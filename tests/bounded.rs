@@ -0,0 +1,71 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::{AutoTickerFactory, MockTickerFactory};
+use wasm_ticker::{mock, Bounded, Bounds, Finish, State, Ticker};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+fn counter() -> (Rc<Cell<u32>>, impl FnMut() + 'static) {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    (n, move || n_.set(n_.get() + 1))
+}
+
+#[wasm_bindgen_test]
+async fn max_ticks() -> Result<(), JsValue> {
+    mock::reset();
+    let (n, task) = counter();
+    let ticker = Bounded::<MockTickerFactory>::new(Bounds::new().max_ticks(3), task)?;
+    let completion = ticker.completion();
+    ticker.start()?;
+    mock::advance(5);
+    assert_eq!(n.get(), 3);
+    assert_eq!(ticker.ticks(), 3);
+    assert_eq!(ticker.state(), State::Stopped);
+    assert_eq!(completion.await, Ok(Finish::MaxTicks));
+
+    // Counting restarts
+    ticker.start_immediate()?;
+    assert_eq!(ticker.ticks(), 1);
+    mock::advance(5);
+    assert_eq!(n.get(), 6);
+    assert_eq!(ticker.completion().await, Ok(Finish::MaxTicks));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn deadline() -> Result<(), JsValue> {
+    let (n, task) = counter();
+    let ticker =
+        Bounded::<AutoTickerFactory>::new(Bounds::new().deadline(Duration::from_millis(50)), task)?;
+    ticker.start()?;
+    assert_eq!(ticker.completion().await, Ok(Finish::Deadline));
+    assert_eq!(ticker.state(), State::Stopped);
+    assert!(n.get() > 0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn predicate_and_stop() -> Result<(), JsValue> {
+    mock::reset();
+    let (n, task) = counter();
+    let n_ = n.clone();
+    let ticker =
+        Bounded::<MockTickerFactory>::new(Bounds::new().until(move || n_.get() == 4), task)?;
+    ticker.start()?;
+    mock::advance(10);
+    assert_eq!(n.get(), 4);
+    assert_eq!(ticker.completion().await, Ok(Finish::Predicate));
+
+    ticker.set_bounds(Bounds::new());
+    ticker.start()?;
+    let completion = ticker.completion();
+    mock::advance(2);
+    ticker.stop();
+    assert_eq!(completion.await, Ok(Finish::Stopped));
+    assert_eq!(n.get(), 6);
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use wasm_bindgen_test::__rt::detect::Runtime;
 use wasm_bindgen_test::*;
 use wasm_ticker::ticker::{
-    AnimationFrameTicker, ImmediateTicker, MessageChannelTicker, TimeoutTicker,
+    AnimationFrameTicker, ImmediateTicker, MessageChannelTicker, TimeoutTicker, WaitAsyncTicker,
 };
 use wasm_ticker::NamedTicker;
 
@@ -15,6 +15,7 @@ fn check_test() {
         timeout: bool,
         immediate: bool,
         animation_frame: bool,
+        wait_async: bool,
     }
     let rt = __rt::detect::detect();
     let correct = match rt {
@@ -23,18 +24,21 @@ fn check_test() {
             timeout: true,
             immediate: false,
             animation_frame: true,
+            wait_async: false,
         },
         Runtime::Node => Res {
             message_channel: true,
             timeout: true,
             immediate: true,
             animation_frame: false,
+            wait_async: true,
         },
         Runtime::Worker => Res {
             message_channel: true,
             timeout: true,
             immediate: true,
             animation_frame: false,
+            wait_async: false,
         },
     };
     let res = Res {
@@ -42,6 +46,18 @@ fn check_test() {
         timeout: TimeoutTicker::check(),
         immediate: ImmediateTicker::check(),
         animation_frame: AnimationFrameTicker::check(),
+        wait_async: WaitAsyncTicker::check(),
     };
     assert_eq!(res, correct);
 }
+
+#[wasm_bindgen_test]
+fn runtime_test() {
+    // Deno and Bun are not launched by `wasm-bindgen-test-runner`
+    let correct = match __rt::detect::detect() {
+        Runtime::Browser => wasm_ticker::Runtime::Browser,
+        Runtime::Node => wasm_ticker::Runtime::Node,
+        Runtime::Worker => wasm_ticker::Runtime::Worker,
+    };
+    assert_eq!(wasm_ticker::Runtime::current(), correct);
+}
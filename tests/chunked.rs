@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::chunked::{fold_chunked, for_each_chunked, ChunkSize, Progress};
+use wasm_ticker::factory::{AutoTickerFactory, MockTickerFactory};
+use wasm_ticker::{mock, TickerError};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn fixed_chunks() -> Result<(), JsValue> {
+    mock::reset();
+    let progress = Rc::new(RefCell::new(Vec::new()));
+    let progress_ = progress.clone();
+    let sum =
+        fold_chunked::<MockTickerFactory, _, _, _>(0..10u32, ChunkSize::Items(4), 0, |sum, i| {
+            sum + i
+        })?;
+    sum.on_progress(move |p| progress_.borrow_mut().push(p));
+    assert_eq!(sum.processed(), 0);
+    mock::advance(1);
+    assert_eq!(sum.processed(), 4);
+    mock::advance(5);
+    assert!(sum.is_finished());
+    assert_eq!(sum.await, Ok(45));
+    let total = Some(10);
+    assert_eq!(
+        *progress.borrow(),
+        [
+            Progress {
+                processed: 4,
+                total
+            },
+            Progress {
+                processed: 8,
+                total
+            },
+            Progress {
+                processed: 10,
+                total
+            },
+        ]
+    );
+    assert!(mock::is_idle());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn cancel() -> Result<(), JsValue> {
+    mock::reset();
+    let seen = Rc::new(RefCell::new(0u32));
+    let seen_ = seen.clone();
+    let task =
+        for_each_chunked::<MockTickerFactory, _, _>(0.., ChunkSize::Items(10), move |_: u32| {
+            *seen_.borrow_mut() += 1
+        })?;
+    mock::advance(2);
+    task.cancel();
+    mock::advance(2);
+    assert_eq!(*seen.borrow(), 20);
+    assert_eq!(task.await, Err(TickerError::Cancelled));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn budget() -> Result<(), JsValue> {
+    let sizes = Rc::new(RefCell::new(Vec::new()));
+    let sizes_ = sizes.clone();
+    let mut last = 0;
+    let sum = fold_chunked::<AutoTickerFactory, _, _, _>(
+        0..200_000u64,
+        ChunkSize::Budget(Duration::from_millis(5)),
+        0,
+        |sum, i| sum + i,
+    )?;
+    sum.on_progress(move |p| {
+        sizes_.borrow_mut().push(p.processed - last);
+        last = p.processed;
+    });
+    assert_eq!(sum.await, Ok(19_999_900_000));
+    let sizes = sizes.borrow();
+    // Starting from one item, growing with measured cost
+    assert_eq!(sizes[0], 1);
+    assert!(sizes.len() < 1000);
+    Ok(())
+}
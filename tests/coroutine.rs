@@ -0,0 +1,50 @@
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::coroutine::Coroutine;
+use wasm_ticker::factory::{AutoTickerFactory, MockTickerFactory};
+use wasm_ticker::time::sleep;
+use wasm_ticker::{mock, State, Ticker};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn resume_on_ticks() -> Result<(), JsValue> {
+    mock::reset();
+    let mut coroutine = Coroutine::<MockTickerFactory, _>::new(|yielder| async move {
+        let mut sum = 0;
+        for i in 0..5u32 {
+            sum += i;
+            yielder.yield_tick().await;
+        }
+        (sum, yielder.ticks())
+    })?;
+    coroutine.start()?;
+    mock::advance(3);
+    assert!(!coroutine.is_finished());
+
+    // Paused while stopped
+    coroutine.stop();
+    mock::advance(3);
+    assert!(!coroutine.is_finished());
+
+    coroutine.start()?;
+    mock::advance(3);
+    assert!(coroutine.is_finished());
+    assert_eq!(coroutine.state(), State::Stopped);
+    assert_eq!((&mut coroutine).await, Ok((10, 6)));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn await_other_futures() -> Result<(), JsValue> {
+    let coroutine = Coroutine::<AutoTickerFactory, _>::new(|yielder| async move {
+        yielder.yield_tick().await;
+        sleep(Duration::from_millis(20)).await.unwrap();
+        yielder.yield_tick().await;
+        "done"
+    })?;
+    coroutine.start()?;
+    assert_eq!(coroutine.await?, "done");
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::debounce::{DebounceOptions, Debounced, Throttled};
+use wasm_ticker::time::sleep;
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+fn recorder() -> (Rc<RefCell<Vec<u32>>>, impl FnMut(u32) + 'static) {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let calls_ = calls.clone();
+    (calls, move |arg| calls_.borrow_mut().push(arg))
+}
+
+#[wasm_bindgen_test]
+async fn debounce_trailing() -> Result<(), JsValue> {
+    let (calls, f) = recorder();
+    let debounced = Debounced::new(DebounceOptions::new(Duration::from_millis(30)), f);
+    for i in 0..5 {
+        debounced.call(i)?;
+        sleep(Duration::from_millis(5)).await?;
+    }
+    assert!(debounced.is_pending());
+    assert!(calls.borrow().is_empty());
+    sleep(Duration::from_millis(60)).await?;
+    assert_eq!(*calls.borrow(), [4]);
+    assert!(!debounced.is_pending());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn debounce_leading_and_max_wait() -> Result<(), JsValue> {
+    let (calls, f) = recorder();
+    let debounced = Debounced::new(
+        DebounceOptions {
+            wait: Duration::from_millis(30),
+            leading: true,
+            trailing: false,
+            max_wait: None,
+        },
+        f,
+    );
+    debounced.call(1)?;
+    debounced.call(2)?;
+    assert_eq!(*calls.borrow(), [1]);
+    sleep(Duration::from_millis(60)).await?;
+    assert_eq!(*calls.borrow(), [1]);
+
+    let (calls, f) = recorder();
+    let debounced = Debounced::new(
+        DebounceOptions {
+            max_wait: Some(Duration::from_millis(40)),
+            ..DebounceOptions::new(Duration::from_millis(30))
+        },
+        f,
+    );
+    for i in 0..20 {
+        debounced.call(i)?;
+        sleep(Duration::from_millis(5)).await?;
+    }
+    // Called during the burst despite calls keep coming
+    assert!(!calls.borrow().is_empty());
+    debounced.cancel();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn flush_and_cancel() -> Result<(), JsValue> {
+    let (calls, f) = recorder();
+    let debounced = Debounced::new(DebounceOptions::new(Duration::from_millis(30)), f);
+    debounced.call(1)?;
+    debounced.flush();
+    assert_eq!(*calls.borrow(), [1]);
+    assert!(!debounced.is_pending());
+
+    debounced.call(2)?;
+    debounced.cancel();
+    sleep(Duration::from_millis(60)).await?;
+    assert_eq!(*calls.borrow(), [1]);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn throttle() -> Result<(), JsValue> {
+    let (calls, f) = recorder();
+    let throttled = Throttled::new(Duration::from_millis(30), f);
+    throttled.call(1)?;
+    throttled.call(2)?;
+    throttled.call(3)?;
+    assert_eq!(*calls.borrow(), [1]);
+    sleep(Duration::from_millis(45)).await?;
+    assert_eq!(*calls.borrow(), [1, 3]);
+    // Still throttled right after trailing edge
+    throttled.call(4)?;
+    assert_eq!(*calls.borrow(), [1, 3]);
+    sleep(Duration::from_millis(45)).await?;
+    assert_eq!(*calls.borrow(), [1, 3, 4]);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn throttle_per_frame() -> Result<(), JsValue> {
+    let (calls, f) = recorder();
+    let throttled = Throttled::per_frame(f);
+    for i in 0..10 {
+        throttled.call(i)?;
+    }
+    assert!(calls.borrow().is_empty());
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(*calls.borrow(), [9]);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn throttle_reentrant() -> Result<(), JsValue> {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let throttled = Rc::new(RefCell::new(None::<Throttled<Box<dyn FnMut(u32)>, u32>>));
+    let calls_ = calls.clone();
+    let throttled_ = throttled.clone();
+    let f: Box<dyn FnMut(u32)> = Box::new(move |arg| {
+        calls_.borrow_mut().push(arg);
+        // Called again on trailing edge, deferred to next one
+        if arg == 2 {
+            if let Some(throttled) = &*throttled_.borrow() {
+                throttled.call(10).unwrap();
+            }
+        }
+    });
+    throttled.replace(Some(Throttled::new(Duration::from_millis(30), f)));
+
+    let t = throttled.borrow().clone().unwrap();
+    t.call(1)?;
+    t.call(2)?;
+    sleep(Duration::from_millis(45)).await?;
+    assert_eq!(*calls.borrow(), [1, 2]);
+    sleep(Duration::from_millis(45)).await?;
+    assert_eq!(*calls.borrow(), [1, 2, 10]);
+    throttled.borrow_mut().take();
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+#[cfg(target_family = "wasm")]
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_ticker::factory::MockTickerFactory;
+use wasm_ticker::mock;
+use wasm_ticker::ticker::FramePacing;
+use wasm_ticker::{Ticker, TickerError, TickerFactory};
+
+/// Frames of a 60Hz display.
+const FRAME: Duration = Duration::from_micros(16_667);
+
+/// Feed virtual time of each round to `pacing` for 2s, returning ticks.
+fn run(pacing: &Rc<RefCell<FramePacing>>) -> Result<u32, TickerError> {
+    mock::reset();
+    mock::set_period(FRAME);
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let pacing_ = pacing.clone();
+    let ticker = MockTickerFactory::new(move || {
+        let timestamp = mock::now().as_secs_f64() * 1000.0;
+        if pacing_.borrow_mut().frame(timestamp) {
+            n_.set(n_.get() + 1);
+        }
+    })?;
+    ticker.start()?;
+    mock::advance_time(Duration::from_secs(2));
+    Ok(n.get())
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn target_fps() -> Result<(), TickerError> {
+    let pacing = Rc::new(RefCell::new(FramePacing::new(Some(20.0))));
+    assert_eq!(pacing.borrow().target_fps(), Some(20.0));
+    assert_eq!(run(&pacing)?, 40);
+
+    let pacing = pacing.borrow();
+    assert!((pacing.refresh_rate().unwrap() - 60.0).abs() < 0.1);
+    assert!((pacing.fps().unwrap() - 20.0).abs() < 0.1);
+    Ok(())
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn every_frame() -> Result<(), TickerError> {
+    let pacing = Rc::new(RefCell::new(FramePacing::new(Some(20.0))));
+    pacing.borrow_mut().set_target_fps(None);
+    assert_eq!(pacing.borrow().target_fps(), None);
+    assert_eq!(run(&pacing)?, 119);
+    assert!((pacing.borrow().fps().unwrap() - 60.0).abs() < 0.1);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{FrameScheduler, State, Ticker};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn coalesce_and_phases() -> Result<(), JsValue> {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let scheduler = Rc::new(FrameScheduler::new()?);
+    assert!(scheduler.is_idle());
+
+    let push = |entry: &'static str| {
+        let log = log.clone();
+        move || log.borrow_mut().push(entry)
+    };
+    scheduler.write("a", push("write a1"))?;
+    scheduler.read("a", push("read a"))?;
+    scheduler.write("b", push("write b"))?;
+    scheduler.write("a", push("write a2"))?;
+    scheduler.write("c", push("write c"))?;
+    assert!(scheduler.cancel(&"c"));
+
+    let scheduler_ = scheduler.clone();
+    let log_ = log.clone();
+    scheduler.read("d", move || {
+        log_.borrow_mut().push("read d");
+        let log = log_.clone();
+        scheduler_
+            .write("d", move || log.borrow_mut().push("write d"))
+            .unwrap();
+    })?;
+    assert_eq!(scheduler.ticker().state(), State::Started);
+    assert!(log.borrow().is_empty());
+
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(
+        *log.borrow(),
+        ["read a", "read d", "write a2", "write b", "write d"]
+    );
+    assert!(scheduler.is_idle());
+    assert_eq!(scheduler.ticker().state(), State::Stopped);
+
+    scheduler.write("a", push("write a3"))?;
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(log.borrow().last(), Some(&"write a3"));
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::{AutoTickerFactory, TimeoutTickerFactory};
+use wasm_ticker::ticker::TimeoutTicker;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{State, Ticker, TickerError, TickerFactory};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &key.into()).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn this_and_args() -> Result<(), JsValue> {
+    let this: JsValue = js_sys::Object::new().into();
+    let function = js_sys::Function::new_with_args("a, b", "this.n = (this.n || 0) + a + b");
+    let args = js_sys::Array::of2(&1.into(), &2.into());
+    let ticker = AutoTickerFactory::new_with_function(function, this.clone(), args)?;
+    ticker.start_immediate()?;
+    assert_eq!(get(&this, "n"), 3);
+    sleep(Duration::from_millis(20)).await?;
+    ticker.stop();
+    assert!(get(&this, "n").as_f64().unwrap() > 3.0);
+    assert_eq!(ticker.state(), State::Stopped);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn capture_exception() -> Result<(), JsValue> {
+    let this: JsValue = js_sys::Object::new().into();
+    let function = js_sys::Function::new_no_args(
+        "this.n = (this.n || 0) + 1; if (this.n === 3) throw 'third';",
+    );
+    let ticker =
+        TimeoutTickerFactory::new_with_function(function, this.clone(), js_sys::Array::new())?;
+    ticker.start()?;
+    sleep(Duration::from_millis(100)).await?;
+    let error = TickerError::Js("third".into());
+    assert_eq!(ticker.state(), State::Error(error.clone()));
+    assert_eq!(get(&this, "n"), 3);
+    assert_eq!(ticker.start(), Err(error));
+
+    ticker.reset();
+    assert_eq!(ticker.state(), State::Stopped);
+    ticker.start()?;
+    sleep(Duration::from_millis(50)).await?;
+    assert!(get(&this, "n").as_f64().unwrap() > 3.0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn looped_in_javascript() -> Result<(), JsValue> {
+    let function = js_sys::Function::new_no_args("throw 'first'");
+    let ticker = TimeoutTickerFactory::new_with_function(
+        function,
+        JsValue::UNDEFINED,
+        js_sys::Array::new(),
+    )?;
+    assert!(ticker.ticker().is_none());
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let states_ = states.clone();
+    ticker.on_state_change(Box::new(move |state| {
+        states_.borrow_mut().push(state.clone())
+    }));
+    ticker.start()?;
+    sleep(Duration::from_millis(20)).await?;
+    let error = State::Error(TickerError::Js("first".into()));
+    assert_eq!(*states.borrow(), [State::Started, error]);
+    Ok(())
+}
+
+/// Ticker keeping the default [Ticker::on_state_change].
+#[derive(Clone, PartialEq, Eq)]
+struct Unobserved(TimeoutTicker);
+
+impl Ticker for Unobserved {
+    fn state(&self) -> State {
+        self.0.state()
+    }
+
+    fn start(&self) -> Result<(), TickerError> {
+        self.0.start()
+    }
+
+    fn start_immediate(&self) -> Result<(), TickerError> {
+        self.0.start_immediate()
+    }
+
+    fn stop(&self) {
+        self.0.stop()
+    }
+
+    fn spawn(task: impl FnOnce() + 'static) -> Result<(), TickerError> {
+        TimeoutTicker::spawn(task)
+    }
+}
+
+struct UnobservedFactory;
+
+impl TickerFactory for UnobservedFactory {
+    type Output = Unobserved;
+
+    fn new(task: impl FnMut() + 'static) -> Result<Self::Output, TickerError> {
+        TimeoutTickerFactory::new(task).map(Unobserved)
+    }
+}
+
+#[wasm_bindgen_test]
+async fn unobserved_state() -> Result<(), JsValue> {
+    let function = js_sys::Function::new_no_args("throw 'first'");
+    let ticker =
+        UnobservedFactory::new_with_function(function, JsValue::UNDEFINED, js_sys::Array::new())?;
+    ticker.start()?;
+    assert_eq!(ticker.state(), State::Started);
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(
+        ticker.state(),
+        State::Error(TickerError::Js("first".into()))
+    );
+    ticker.reset();
+    assert_eq!(ticker.state(), State::Stopped);
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::time::{sleep, Instant};
+use wasm_ticker::{GameLoop, Ticker};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn fixed_timestep() -> Result<(), JsValue> {
+    let updates = Rc::new(Cell::new(0u32));
+    let renders = Rc::new(Cell::new(0u32));
+    let (updates_, renders_) = (updates.clone(), renders.clone());
+    let game_loop = GameLoop::new(
+        Duration::from_millis(10),
+        move |dt| {
+            assert_eq!(dt, Duration::from_millis(10));
+            updates_.set(updates_.get() + 1);
+        },
+        move |alpha| {
+            assert!((0.0..1.0).contains(&alpha));
+            renders_.set(renders_.get() + 1);
+        },
+    )?;
+    game_loop.start()?;
+    sleep(Duration::from_millis(500)).await?;
+    game_loop.stop();
+
+    assert!((40..=55).contains(&updates.get()), "{}", updates.get());
+    assert!(renders.get() > updates.get());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn max_frame_time() -> Result<(), JsValue> {
+    let updates = Rc::new(Cell::new(0u32));
+    let frames = Rc::new(Cell::new(0u32));
+    let stalled_updates = Rc::new(Cell::new(0u32));
+    let (updates_, updates__) = (updates.clone(), updates.clone());
+    let (frames_, stalled_updates_) = (frames.clone(), stalled_updates.clone());
+    let game_loop = GameLoop::new(
+        Duration::from_millis(10),
+        move |_| updates_.set(updates_.get() + 1),
+        move |_| {
+            frames_.set(frames_.get() + 1);
+            match frames_.get() {
+                1 => {
+                    // Stall for 200ms
+                    let start = Instant::now();
+                    while start.elapsed() < Duration::from_millis(200) {}
+                }
+                2 => stalled_updates_.set(updates__.get()),
+                _ => (),
+            }
+        },
+    )?;
+    game_loop.set_max_frame_time(Duration::from_millis(50));
+    game_loop.start()?;
+    while frames.get() < 2 {
+        sleep(Duration::from_millis(10)).await?;
+    }
+    game_loop.stop();
+
+    assert_eq!(stalled_updates.get(), 5);
+    Ok(())
+}
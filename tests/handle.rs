@@ -0,0 +1,54 @@
+#![cfg(feature = "atomics")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::AutoTickerFactory;
+use wasm_ticker::sync::{Response, TickerHandle};
+use wasm_ticker::time::sleep;
+use wasm_ticker::{State, Ticker, TickerError, TickerFactory};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[wasm_bindgen_test]
+async fn forward_requests() -> Result<(), JsValue> {
+    assert_send_sync::<TickerHandle>();
+    assert_send_sync::<Response>();
+
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let handle = TickerHandle::new(AutoTickerFactory::new(move || n_.set(n_.get() + 1))?);
+    assert_eq!(handle.state(), State::Stopped);
+
+    let handle_ = handle.clone();
+    handle_.start().await?;
+    assert_eq!(handle.start().await, Err(TickerError::AlreadyStarted));
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(handle.state(), State::Started);
+    assert!(n.get() > 0);
+
+    drop(handle_);
+    handle.stop();
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(handle.state(), State::Stopped);
+    let stopped = n.get();
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(n.get(), stopped);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn mirror_state_changes() -> Result<(), JsValue> {
+    let ticker = AutoTickerFactory::new(|| ())?;
+    let handle = TickerHandle::new(ticker.clone());
+    handle.start().await?;
+    assert_eq!(handle.state(), State::Started);
+    // Stopped on owning thread without a request
+    ticker.stop();
+    assert_eq!(handle.state(), State::Stopped);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "js-api")]
+
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::js::{WasmTicker, WasmTickerState};
+use wasm_ticker::time::sleep;
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+fn counter(name: &str) -> js_sys::Function {
+    js_sys::Function::new_no_args(&format!("globalThis.{0} = (globalThis.{0} || 0) + 1", name))
+}
+
+fn count(name: &str) -> f64 {
+    js_sys::Reflect::get(&js_sys::global(), &name.into())
+        .unwrap()
+        .as_f64()
+        .unwrap_or(0.0)
+}
+
+#[wasm_bindgen_test]
+async fn ticker_class() -> Result<(), JsValue> {
+    let ticker = WasmTicker::new(None, counter("__js_ticks"))?;
+    assert_ne!(ticker.backend(), "auto");
+    assert_eq!(ticker.state(), WasmTickerState::Stopped);
+    ticker.start_immediate()?;
+    assert_eq!(count("__js_ticks"), 1.0);
+    assert_eq!(ticker.state(), WasmTickerState::Started);
+    assert!(ticker.start().is_err());
+    sleep(Duration::from_millis(20)).await?;
+    ticker.stop();
+    assert!(count("__js_ticks") > 1.0);
+    assert!(ticker.error().is_undefined());
+
+    let ticker = WasmTicker::new(Some("timeout".to_string()), counter("__js_timeout"))?;
+    assert_eq!(ticker.backend(), "timeout");
+    assert!(WasmTicker::new(Some("unknown".to_string()), counter("__js_unknown")).is_err());
+
+    WasmTicker::spawn(counter("__js_spawn"), None, None)?;
+    sleep(Duration::from_millis(20)).await?;
+    assert_eq!(count("__js_spawn"), 1.0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn abort() -> Result<(), JsValue> {
+    let controller = web_sys::AbortController::new()?;
+    let ticker = WasmTicker::new(None, counter("__js_abort_ticks"))?;
+    let signal = ticker.abort_signal()?;
+    ticker.stop_on_abort(&controller.signal())?;
+    ticker.start()?;
+    controller.abort();
+    assert_eq!(ticker.state(), WasmTickerState::Stopped);
+    assert!(signal.aborted());
+
+    WasmTicker::spawn(counter("__js_abort_spawn"), None, Some(controller.signal())).unwrap_err();
+    sleep(Duration::from_millis(20)).await?;
+    assert_eq!(count("__js_abort_spawn"), 0.0);
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+#[cfg(target_family = "wasm")]
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_ticker::factory::MockTickerFactory;
+use wasm_ticker::mock::{self, MockEvent, MockSource};
+use wasm_ticker::ticker::MockTicker;
+use wasm_ticker::{State, Ticker, TickerError, TickerFactory};
+
+fn counter() -> (Rc<Cell<u32>>, impl FnMut() + 'static) {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    (n, move || n_.set(n_.get() + 1))
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn advance() -> Result<(), TickerError> {
+    mock::reset();
+    let (n, task) = counter();
+    let ticker = MockTickerFactory::new(task)?;
+    ticker.start()?;
+    assert_eq!(n.get(), 0);
+    mock::advance(3);
+    assert_eq!(n.get(), 3);
+
+    ticker.stop();
+    assert_eq!(ticker.state(), State::Stopped);
+    mock::advance(3);
+    assert_eq!(n.get(), 3);
+    assert!(mock::is_idle());
+
+    // Restart before queued tick runs, still once per round
+    ticker.start()?;
+    ticker.stop();
+    ticker.start()?;
+    mock::advance(2);
+    assert_eq!(n.get(), 5);
+    Ok(())
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn advance_time() -> Result<(), TickerError> {
+    mock::reset();
+    mock::set_period(Duration::from_millis(4));
+    let (n, task) = counter();
+    let ticker = MockTickerFactory::new(task)?;
+    ticker.start_immediate()?;
+    assert_eq!(n.get(), 1);
+    mock::advance_time(Duration::from_millis(10));
+    assert_eq!(n.get(), 3);
+    assert_eq!(mock::now(), Duration::from_millis(10));
+    assert_eq!(mock::rounds(), 2);
+    Ok(())
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn schedule() -> Result<(), TickerError> {
+    mock::reset();
+    let (_, task) = counter();
+    let a = MockTickerFactory::new(task)?;
+    let b_ = Rc::new(Cell::new(None::<MockTicker>));
+    let b__ = b_.clone();
+    let b = MockTickerFactory::new(move || {
+        // Stop itself on first tick, spawn once
+        if let Some(b) = b__.take() {
+            b.stop();
+            MockTicker::spawn(|| ()).unwrap();
+        }
+    })?;
+    b_.set(Some(b.clone()));
+
+    a.start()?;
+    b.start()?;
+    mock::advance(3);
+    let event = |round: u64, source: MockSource| MockEvent {
+        round,
+        time: Duration::from_millis(round),
+        source,
+    };
+    assert_eq!(
+        mock::schedule(),
+        vec![
+            event(1, MockSource::Ticker(a.id())),
+            event(1, MockSource::Ticker(b.id())),
+            event(2, MockSource::Ticker(a.id())),
+            event(2, MockSource::Spawn),
+            event(3, MockSource::Ticker(a.id())),
+        ]
+    );
+    Ok(())
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn dropped() -> Result<(), TickerError> {
+    mock::reset();
+    let (n, task) = counter();
+    MockTickerFactory::new(task)?.start()?;
+    mock::advance(2);
+    assert_eq!(n.get(), 0);
+    assert!(mock::is_idle());
+    Ok(())
+}
+
+#[cfg_attr(target_family = "wasm", wasm_bindgen_test)]
+#[cfg_attr(not(target_family = "wasm"), test)]
+fn reset_queued() -> Result<(), TickerError> {
+    mock::reset();
+    let (n, task) = counter();
+    let ticker = MockTickerFactory::new(task)?;
+    ticker.start()?;
+    // Queued tick is dropped with the clock, restart queues a new one
+    mock::reset();
+    ticker.stop();
+    ticker.start()?;
+    mock::advance(2);
+    assert_eq!(n.get(), 2);
+    Ok(())
+}
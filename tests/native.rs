@@ -0,0 +1,262 @@
+#![cfg(not(target_family = "wasm"))]
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_ticker::chunked::{for_each_chunked, ChunkSize};
+use wasm_ticker::coroutine::Coroutine;
+use wasm_ticker::debounce::{DebounceOptions, Debounced};
+use wasm_ticker::factory::AutoTickerFactory;
+use wasm_ticker::task_graph::TaskGraph;
+use wasm_ticker::ticker::AutoTicker;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{
+    native, Bounded, Bounds, GameLoop, Runtime, State, Ticker, TickerError, TickerFactory,
+};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[test]
+fn auto_selects_native() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker_ = Rc::new(Cell::new(None::<AutoTicker>));
+    let ticker__ = ticker_.clone();
+    let ticker = AutoTickerFactory::new(move || {
+        n_.set(n_.get() + 1);
+        if n_.get() == 10 {
+            if let Some(ticker) = ticker__.take() {
+                ticker.stop();
+            }
+        }
+    })
+    .unwrap();
+    assert!(matches!(ticker, AutoTicker::Native(_)));
+    ticker_.set(Some(ticker.clone()));
+
+    ticker.start().unwrap();
+    native::run();
+    assert_eq!(n.get(), 10);
+    assert_eq!(ticker.state(), State::Stopped);
+    assert!(native::is_idle());
+}
+
+#[test]
+fn runtime_unknown() {
+    assert_eq!(Runtime::current(), Runtime::Unknown);
+}
+
+#[test]
+fn run_for() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = AutoTickerFactory::new(move || n_.set(n_.get() + 1)).unwrap();
+    ticker.start_immediate().unwrap();
+    assert_eq!(n.get(), 1);
+    assert!(native::run_for(Duration::from_millis(10)));
+    assert!(n.get() > 1);
+
+    ticker.stop();
+    native::run();
+    let stopped = n.get();
+    assert_eq!(native::run_once(), 0);
+    assert_eq!(n.get(), stopped);
+}
+
+#[test]
+fn spawn() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    AutoTicker::spawn(move || n_.set(n_.get() + 1)).unwrap();
+    assert_eq!(n.get(), 0);
+    assert_eq!(native::run_once(), 1);
+    assert_eq!(n.get(), 1);
+}
+
+#[test]
+fn game_loop_spawn() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    GameLoop::spawn(move || n_.set(n_.get() + 1)).unwrap();
+    assert_eq!(native::run_once(), 1);
+    assert_eq!(n.get(), 1);
+}
+
+#[test]
+fn errors() {
+    let ticker = AutoTickerFactory::new(|| panic!("task panicked")).unwrap();
+    ticker.start().unwrap();
+    assert_eq!(ticker.start(), Err(TickerError::AlreadyStarted));
+    native::run();
+    assert_eq!(ticker.state(), State::Error(TickerError::CallbackPanicked));
+    assert_eq!(ticker.start(), Err(TickerError::CallbackPanicked));
+}
+
+#[test]
+fn reset_after_error() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = AutoTickerFactory::new(move || {
+        n_.set(n_.get() + 1);
+        if n_.get() == 1 {
+            panic!("task panicked");
+        }
+    })
+    .unwrap();
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let states_ = states.clone();
+    ticker.on_state_change(Box::new(move |state| {
+        states_.borrow_mut().push(state.clone())
+    }));
+
+    ticker.start().unwrap();
+    native::run_once();
+    assert_eq!(ticker.state(), State::Error(TickerError::CallbackPanicked));
+    ticker.reset();
+    assert_eq!(ticker.state(), State::Stopped);
+    ticker.start_immediate().unwrap();
+    assert_eq!(n.get(), 2);
+    ticker.stop();
+    native::run();
+
+    assert_eq!(
+        *states.borrow(),
+        [
+            State::Started,
+            State::Error(TickerError::CallbackPanicked),
+            State::Stopped,
+            State::Started,
+            State::Stopped,
+        ]
+    );
+}
+
+#[test]
+fn bounded() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = Bounded::<AutoTickerFactory>::new(Bounds::new().max_ticks(10), move || {
+        n_.set(n_.get() + 1)
+    })
+    .unwrap();
+    ticker.start().unwrap();
+    native::run();
+    assert_eq!(n.get(), 10);
+    assert_eq!(ticker.ticks(), 10);
+    assert_eq!(ticker.state(), State::Stopped);
+}
+
+#[test]
+fn chunked() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let task = for_each_chunked::<AutoTickerFactory, _, _>(
+        0..1000,
+        ChunkSize::Budget(Duration::from_millis(1)),
+        move |_: u32| n_.set(n_.get() + 1),
+    )
+    .unwrap();
+    native::run();
+    assert_eq!(n.get(), 1000);
+    assert_eq!(task.processed(), 1000);
+    assert!(task.is_finished());
+}
+
+#[test]
+fn chunked_error() {
+    let task = for_each_chunked::<AutoTickerFactory, _, _>(0..10, ChunkSize::Items(1), |i: u32| {
+        if i == 5 {
+            panic!("item panicked");
+        }
+    })
+    .unwrap();
+    native::run();
+    assert!(task.is_finished());
+    assert_eq!(task.processed(), 5);
+}
+
+#[test]
+fn task_graph() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let mut graph = TaskGraph::new();
+    let a = graph.add(&[], |_| Ok(20u32));
+    graph.add(&[a.id()], move |r| {
+        n_.set(r.get(a).unwrap() + 1);
+        Ok(())
+    });
+    let run = graph.run::<AutoTickerFactory>(1).unwrap();
+    assert_eq!(n.get(), 0);
+    native::run();
+    assert!(run.is_finished());
+    assert_eq!(n.get(), 21);
+}
+
+#[test]
+fn coroutine() {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let coroutine = Coroutine::<AutoTickerFactory, _>::new(|yielder| async move {
+        for _ in 0..10 {
+            n_.set(n_.get() + 1);
+            yielder.yield_tick().await;
+        }
+    })
+    .unwrap();
+    coroutine.start().unwrap();
+    native::run_once();
+    assert_eq!(n.get(), 1);
+    native::run();
+    assert_eq!(n.get(), 10);
+    assert!(coroutine.is_finished());
+}
+
+#[test]
+fn coroutine_error() {
+    let mut coroutine = Coroutine::<AutoTickerFactory, _>::new(|yielder| async move {
+        yielder.yield_tick().await;
+        panic!("body panicked");
+    })
+    .unwrap();
+    coroutine.start().unwrap();
+    native::run();
+    assert!(coroutine.is_finished());
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let output = Pin::new(&mut coroutine).poll(&mut Context::from_waker(&waker));
+    assert_eq!(output, Poll::Ready(Err(TickerError::CallbackPanicked)));
+}
+
+#[test]
+fn js_apis_unavailable() {
+    let unavailable = |api| Err(TickerError::Unavailable(api));
+    let ticker = AutoTickerFactory::new(|| ()).unwrap();
+    assert_eq!(
+        ticker.abort_signal().err(),
+        unavailable("AbortController").err()
+    );
+    assert_eq!(
+        AutoTicker::spawn_promise(|| Ok(JsValue::UNDEFINED)).err(),
+        unavailable("Promise.withResolvers").err()
+    );
+    let debounced = Debounced::new(DebounceOptions::new(Duration::from_millis(1)), |_: ()| ());
+    assert_eq!(debounced.call(()), unavailable("setTimeout"));
+
+    let result = Rc::new(RefCell::new(None));
+    let result_ = result.clone();
+    let coroutine = Coroutine::<AutoTickerFactory, _>::new(|_| async move {
+        *result_.borrow_mut() = Some(sleep(Duration::from_millis(1)).await);
+    })
+    .unwrap();
+    coroutine.start().unwrap();
+    native::run();
+    assert_eq!(*result.borrow(), Some(unavailable("setTimeout")));
+}
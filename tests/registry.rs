@@ -0,0 +1,75 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::{DynTickerFactory, TickerRegistry, TimeoutTickerFactory};
+use wasm_ticker::ticker::BoxedTicker;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{Ticker, TickerError};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+/// Custom backend never available.
+struct Unavailable;
+
+impl DynTickerFactory for Unavailable {
+    fn check(&self) -> bool {
+        false
+    }
+
+    fn create(&self, _: Box<dyn FnMut()>) -> Result<BoxedTicker, TickerError> {
+        Err(TickerError::Unavailable("Unavailable"))
+    }
+
+    fn spawn(&self, _: Box<dyn FnOnce()>) -> Result<(), TickerError> {
+        Err(TickerError::Unavailable("Unavailable"))
+    }
+}
+
+#[wasm_bindgen_test]
+async fn select() -> Result<(), JsValue> {
+    let mut registry = TickerRegistry::empty();
+    assert!(registry.select().is_none());
+    assert_eq!(
+        registry.create(|| ()).err(),
+        Some(TickerError::NoBackendAvailable)
+    );
+
+    registry
+        .register_first("timeout", TimeoutTickerFactory)
+        .register_first("unavailable", Unavailable);
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        vec!["unavailable", "timeout"]
+    );
+    assert_eq!(registry.select().map(|(name, _)| name), Some("timeout"));
+    assert_eq!(
+        registry.create_named("unavailable", || ()).err(),
+        Some(TickerError::Unavailable("Unavailable"))
+    );
+    assert_eq!(
+        registry.create_named("unknown", || ()).err(),
+        Some(TickerError::NotRegistered("unknown".to_string()))
+    );
+
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = registry.create(move || n_.set(n_.get() + 1))?;
+    ticker.start()?;
+    sleep(Duration::from_millis(50)).await?;
+    ticker.stop();
+    assert!(n.get() > 0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn builtins() -> Result<(), JsValue> {
+    let registry = TickerRegistry::default();
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    registry.spawn(move || n_.set(n_.get() + 1))?;
+    sleep(Duration::from_millis(20)).await?;
+    assert_eq!(n.get(), 1);
+    Ok(())
+}
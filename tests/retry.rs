@@ -0,0 +1,157 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::BackendTickerFactory;
+use wasm_ticker::ticker::{RetryPolicy, RetryTicker};
+use wasm_ticker::time::sleep;
+use wasm_ticker::{ScheduleBackend, State, Ticker, TickerError, TickerFactory};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_name = setTimeout)]
+    fn set_timeout(cb: &js_sys::Function) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(token: JsValue);
+}
+
+thread_local! {
+    static FAILURES: Cell<u32> = Cell::new(0);
+}
+
+/// `setTimeout` failing while [FAILURES] remain.
+struct FlakyBackend;
+
+impl ScheduleBackend for FlakyBackend {
+    type Token = JsValue;
+
+    fn available() -> bool {
+        true
+    }
+
+    fn schedule(cb: &js_sys::Function) -> Result<JsValue, JsValue> {
+        let failures = FAILURES.with(Cell::get);
+        if failures > 0 {
+            FAILURES.with(|n| n.set(failures - 1));
+            return Err(JsValue::from_str("flaky"));
+        }
+        set_timeout(cb)
+    }
+
+    fn cancel(token: JsValue) {
+        clear_timeout(token)
+    }
+}
+
+fn policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries: Some(max_retries),
+        initial_delay: Duration::from_millis(5),
+        ..RetryPolicy::default()
+    }
+}
+
+#[wasm_bindgen_test]
+fn backoff() {
+    let policy = RetryPolicy {
+        max_retries: Some(3),
+        initial_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(300),
+        multiplier: 2.0,
+    };
+    assert_eq!(policy.delay(0), Some(Duration::from_millis(100)));
+    assert_eq!(policy.delay(1), Some(Duration::from_millis(200)));
+    assert_eq!(policy.delay(2), Some(Duration::from_millis(300)));
+    assert_eq!(policy.delay(3), None);
+
+    let policy = RetryPolicy {
+        multiplier: -2.0,
+        ..policy
+    };
+    assert_eq!(policy.delay(1), Some(Duration::ZERO));
+    let policy = RetryPolicy {
+        initial_delay: Duration::ZERO,
+        multiplier: f64::INFINITY,
+        ..policy
+    };
+    assert_eq!(policy.delay(1), Some(Duration::ZERO));
+}
+
+#[wasm_bindgen_test]
+async fn retry_until_scheduled() -> Result<(), JsValue> {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = RetryTicker::new(
+        BackendTickerFactory::<FlakyBackend>::new(move || n_.set(n_.get() + 1))?,
+        policy(5),
+    );
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let states_ = states.clone();
+    ticker.on_state_change(Box::new(move |s| states_.borrow_mut().push(s.clone())));
+
+    FAILURES.with(|n| n.set(2));
+    let error = TickerError::ScheduleFailed(JsValue::from_str("flaky"));
+    assert_eq!(ticker.start(), Err(error.clone()));
+    assert_eq!(ticker.attempts(), 1);
+    sleep(Duration::from_millis(100)).await?;
+    assert_eq!(ticker.state(), State::Started);
+    assert_eq!(ticker.attempts(), 2);
+    assert!(n.get() > 0);
+    ticker.stop();
+
+    assert_eq!(
+        states.borrow()[..6],
+        [
+            State::Started,
+            State::Error(error.clone()),
+            State::Stopped,
+            State::Started,
+            State::Error(error),
+            State::Stopped,
+        ]
+    );
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn give_up() -> Result<(), JsValue> {
+    let ticker = RetryTicker::new(BackendTickerFactory::<FlakyBackend>::new(|| ())?, policy(2));
+    FAILURES.with(|n| n.set(10));
+    assert!(ticker.start().is_err());
+    sleep(Duration::from_millis(100)).await?;
+    assert_eq!(ticker.attempts(), 2);
+    assert!(matches!(
+        ticker.state(),
+        State::Error(TickerError::ScheduleFailed(_))
+    ));
+    FAILURES.with(|n| n.set(0));
+
+    ticker.reset();
+    assert_eq!(ticker.attempts(), 0);
+    assert_eq!(ticker.state(), State::Stopped);
+    ticker.start()?;
+    assert_eq!(ticker.state(), State::Started);
+    ticker.stop();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn start_while_retrying() -> Result<(), JsValue> {
+    let ticker = RetryTicker::new(BackendTickerFactory::<FlakyBackend>::new(|| ())?, policy(5));
+    FAILURES.with(|n| n.set(1));
+    assert!(ticker.start().is_err());
+    assert_eq!(ticker.attempts(), 1);
+
+    // Starts afresh instead of returning the stored error
+    ticker.start()?;
+    assert_eq!(ticker.state(), State::Started);
+    assert_eq!(ticker.attempts(), 0);
+    sleep(Duration::from_millis(50)).await?;
+    assert_eq!(ticker.state(), State::Started);
+    assert_eq!(ticker.attempts(), 0);
+    ticker.stop();
+    Ok(())
+}
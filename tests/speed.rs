@@ -1,45 +1,17 @@
 use std::ops::AddAssign;
 use std::rc::Rc;
+use std::time::Duration;
 use wasm_bindgen::__rt::WasmRefCell;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::JsFuture;
+use wasm_ticker::time::sleep;
 use wasm_ticker::{Ticker, TickerFactory};
 
-#[wasm_bindgen]
-extern "C" {
-    type Promise;
-    #[wasm_bindgen(static_method_of = Promise)]
-    fn withResolvers() -> Resolvers;
-
-    type Resolvers;
-
-    #[wasm_bindgen(method, getter)]
-    fn promise(this: &Resolvers) -> js_sys::Promise;
-    #[wasm_bindgen(method, getter)]
-    fn resolve(this: &Resolvers) -> js_sys::Function;
-
-    #[wasm_bindgen(catch, js_name = "setTimeout")]
-    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> Result<JsValue, JsValue>;
-}
-
-async fn wait(timeout: i32) -> Result<JsValue, JsValue> {
-    let resolvers = Promise::withResolvers();
-    let (promise, resolve) = (resolvers.promise(), resolvers.resolve());
-
-    let cb = Closure::once(move || {
-        resolve.call0(&JsValue::null()).unwrap();
-    });
-
-    set_timeout(cb.as_ref().unchecked_ref(), timeout)?;
-    JsFuture::from(promise).await
-}
-
 async fn speed_test_impl<T: TickerFactory>(timeout: i32) -> Result<u32, JsValue> {
     let times = Rc::new(WasmRefCell::new(0));
     let times_ = times.clone();
     let ticker = T::new(move || times_.borrow_mut().add_assign(1))?;
     ticker.start()?;
-    wait(timeout).await?;
+    sleep(Duration::from_millis(timeout as u64)).await?;
     ticker.stop();
     let times = *times.borrow();
     Ok(times)
@@ -0,0 +1,69 @@
+#![cfg(feature = "futures-core")]
+
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::AutoTickerFactory;
+use wasm_ticker::stream::{Backpressure, TickInfo, TickStream};
+use wasm_ticker::yield_now;
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+struct Next<'a, S>(&'a mut S);
+
+impl<S: Stream + Unpin> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.0).poll_next(cx)
+    }
+}
+
+fn next<S: Stream + Unpin>(stream: &mut S) -> Next<'_, S> {
+    Next(stream)
+}
+
+async fn slow_consume(
+    backpressure: Backpressure,
+) -> Result<(TickInfo, TickInfo, TickInfo), JsValue> {
+    let mut ticks = TickStream::<AutoTickerFactory>::new(backpressure)?;
+    let first = next(&mut ticks).await.unwrap();
+    for _ in 0..100 {
+        yield_now().await?;
+    }
+    let second = next(&mut ticks).await.unwrap();
+    let third = next(&mut ticks).await.unwrap();
+    ticks.close();
+    assert_eq!(next(&mut ticks).await, None);
+    Ok((first, second, third))
+}
+
+#[wasm_bindgen_test]
+async fn drop() -> Result<(), JsValue> {
+    let (first, second, _) = slow_consume(Backpressure::Drop).await?;
+    assert_eq!(first, TickInfo { tick: 1, count: 1 });
+    assert_eq!(second.count, 1);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn coalesce() -> Result<(), JsValue> {
+    let (first, second, third) = slow_consume(Backpressure::Coalesce).await?;
+    assert_eq!(first, TickInfo { tick: 1, count: 1 });
+    assert!(second.count > 1);
+    assert_eq!(second.tick, first.tick + second.count);
+    assert_eq!(third.tick, second.tick + third.count);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn pause() -> Result<(), JsValue> {
+    let (first, second, third) = slow_consume(Backpressure::Pause).await?;
+    assert_eq!(first, TickInfo { tick: 1, count: 1 });
+    assert_eq!(second, TickInfo { tick: 2, count: 1 });
+    assert_eq!(third, TickInfo { tick: 3, count: 1 });
+    Ok(())
+}
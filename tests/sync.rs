@@ -4,38 +4,9 @@ use std::cell::Cell;
 use std::rc::Rc;
 use std::time::Duration;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::JsFuture;
+use wasm_ticker::time::sleep;
 use wasm_ticker::{NamedTicker, Ticker, TickerFactory};
 
-#[wasm_bindgen]
-extern "C" {
-    type Promise;
-    #[wasm_bindgen(static_method_of = Promise)]
-    fn withResolvers() -> Resolvers;
-
-    type Resolvers;
-
-    #[wasm_bindgen(method, getter)]
-    fn promise(this: &Resolvers) -> js_sys::Promise;
-    #[wasm_bindgen(method, getter)]
-    fn resolve(this: &Resolvers) -> js_sys::Function;
-
-    #[wasm_bindgen(catch, js_name = "setTimeout")]
-    fn set_timeout(handler: &js_sys::Function, timeout: i32) -> Result<JsValue, JsValue>;
-}
-
-async fn wait(timeout: i32) -> Result<JsValue, JsValue> {
-    let resolvers = Promise::withResolvers();
-    let (promise, resolve) = (resolvers.promise(), resolvers.resolve());
-
-    let cb = Closure::once(move || {
-        resolve.call0(&JsValue::null()).unwrap();
-    });
-
-    set_timeout(cb.as_ref().unchecked_ref(), timeout)?;
-    JsFuture::from(promise).await
-}
-
 async fn sync_test_impl<F: TickerFactory<Output: NamedTicker>>(
     interval: i32,
     times: u32,
@@ -56,7 +27,7 @@ async fn sync_test_impl<F: TickerFactory<Output: NamedTicker>>(
     assert_eq!(n.get(), m.get());
 
     for i in 0..times {
-        wait(interval).await?;
+        sleep(Duration::from_millis(interval as u64)).await?;
         let n = n.get();
         let m = m.get();
         assert_eq!(
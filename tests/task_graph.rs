@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::{AutoTickerFactory, MockTickerFactory};
+use wasm_ticker::task_graph::{NodeState, TaskGraph};
+use wasm_ticker::{mock, TickerError};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn topological_order() -> Result<(), JsValue> {
+    mock::reset();
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let log = |name: &'static str| {
+        let order = order.clone();
+        move || order.borrow_mut().push(name)
+    };
+    let mut graph = TaskGraph::new();
+    let (log_a, log_b, log_c, log_d) = (log("a"), log("b"), log("c"), log("d"));
+    let a = graph.add(&[], move |_| {
+        log_a();
+        Ok(2u32)
+    });
+    let b = graph.add(&[a.id()], move |r| {
+        log_b();
+        Ok(r.get(a).unwrap() * 10)
+    });
+    let c = graph.add(&[a.id()], move |r| {
+        log_c();
+        Ok(r.get(a).unwrap() + 1)
+    });
+    let d = graph.add(&[b.id(), c.id()], move |r| {
+        log_d();
+        Ok(format!("{}", r.get(b).unwrap() + r.get(c).unwrap()))
+    });
+
+    let run = graph.run::<MockTickerFactory>(1)?;
+    mock::advance(2);
+    assert_eq!(*order.borrow(), ["a", "b"]);
+    mock::advance(2);
+    assert!(run.is_finished());
+    let mut results = run.await?;
+    assert!(results.is_success());
+    assert_eq!(*order.borrow(), ["a", "b", "c", "d"]);
+    assert_eq!(results.take(d), Some("23".to_string()));
+    assert_eq!(results.get(d), None);
+    assert_eq!(results.get(b), Some(&20));
+    assert!(mock::is_idle());
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn failure_cancels_dependents() -> Result<(), JsValue> {
+    let mut graph = TaskGraph::new();
+    let a = graph.add(&[], |_| Ok(1));
+    let b = graph.add::<u32>(&[a.id()], |_| Err("broken".into()));
+    let c = graph.add(&[b.id()], |_| Ok(()));
+    let d = graph.add(&[c.id()], |_| Ok(()));
+    let e = graph.add(&[a.id()], |_| Ok(()));
+
+    let results = graph.run::<AutoTickerFactory>(4)?.await?;
+    assert!(!results.is_success());
+    assert_eq!(*results.state(a.id()), NodeState::Done);
+    assert_eq!(
+        *results.state(b.id()),
+        NodeState::Failed("broken".to_string())
+    );
+    assert_eq!(*results.state(c.id()), NodeState::Cancelled);
+    assert_eq!(*results.state(d.id()), NodeState::Cancelled);
+    assert_eq!(*results.state(e.id()), NodeState::Done);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn cancel() -> Result<(), JsValue> {
+    mock::reset();
+    let mut graph = TaskGraph::new();
+    let a = graph.add(&[], |_| Ok(()));
+    graph.add(&[a.id()], |_| Ok(()));
+    let run = graph.run::<MockTickerFactory>(1)?;
+    mock::advance(1);
+    run.cancel();
+    mock::advance(1);
+    assert_eq!(run.await.err(), Some(TickerError::Cancelled));
+    assert!(mock::is_idle());
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::time::{
+    interval, sleep, sleep_until, timeout, Instant, MissedTickBehavior, TimeoutError,
+};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn sleep_elapsed() -> Result<(), JsValue> {
+    let start = Instant::now();
+    sleep(Duration::from_millis(50)).await?;
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    let deadline = Instant::now() + Duration::from_millis(20);
+    sleep_until(deadline).await?;
+    assert!(Instant::now() >= deadline);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn timeout_completed() -> Result<(), JsValue> {
+    let res = timeout(Duration::from_millis(100), async {
+        sleep(Duration::from_millis(10)).await?;
+        Ok::<_, JsValue>(42)
+    })
+    .await;
+    assert_eq!(res, Ok(Ok(42)));
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn timeout_elapsed() -> Result<(), JsValue> {
+    let start = Instant::now();
+    let res = timeout(Duration::from_millis(10), sleep(Duration::from_secs(60))).await;
+    assert_eq!(res, Err(TimeoutError::Elapsed));
+    assert!(start.elapsed() < Duration::from_secs(60));
+    Ok(())
+}
+
+fn busy_wait(duration: Duration) {
+    let start = Instant::now();
+    while start.elapsed() < duration {}
+}
+
+fn millis(duration: Duration) -> u128 {
+    (duration + Duration::from_micros(500)).as_millis()
+}
+
+async fn late_ticks(behavior: MissedTickBehavior) -> Result<[u128; 3], JsValue> {
+    let mut interval = interval(Duration::from_millis(10));
+    interval.set_missed_tick_behavior(behavior);
+    let start = interval.tick().await?;
+    busy_wait(Duration::from_millis(35));
+    let mut res = [0; 3];
+    for r in res.iter_mut() {
+        *r = millis(interval.tick().await? - start);
+    }
+    Ok(res)
+}
+
+#[wasm_bindgen_test]
+async fn interval_burst() -> Result<(), JsValue> {
+    assert_eq!(late_ticks(MissedTickBehavior::Burst).await?, [10, 20, 30]);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn interval_delay() -> Result<(), JsValue> {
+    let [first, second, third] = late_ticks(MissedTickBehavior::Delay).await?;
+    assert_eq!(first, 10);
+    assert!(second >= 45);
+    assert!(third >= second + 10);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn interval_skip() -> Result<(), JsValue> {
+    assert_eq!(late_ticks(MissedTickBehavior::Skip).await?, [10, 40, 50]);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn interval_reset() -> Result<(), JsValue> {
+    let mut interval = interval(Duration::from_millis(10));
+    interval.tick().await?;
+    interval.set_period(Duration::from_millis(30));
+    interval.reset();
+    let start = Instant::now();
+    interval.tick().await?;
+    assert!(start.elapsed() >= Duration::from_millis(30));
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::{
+    MessageChannelTickerFactory, TimeoutTickerFactory, UnrefImmediateTickerFactory,
+    UnrefTimeoutTickerFactory,
+};
+use wasm_ticker::ticker::ImmediateTicker;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{NamedTicker, Runtime, Ticker, TickerFactory, TimerTicker};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+/// Tokens are objects with `hasRef` only in Node and Bun.
+fn ref_supported() -> bool {
+    matches!(Runtime::current(), Runtime::Node | Runtime::Bun)
+}
+
+#[wasm_bindgen_test]
+fn timeout_token() -> Result<(), JsValue> {
+    let ticker = TimeoutTickerFactory::new(|| ())?;
+    ticker.start()?;
+    let token = ticker.token().unwrap();
+    if ref_supported() {
+        assert_eq!(token.has_ref(), Some(true));
+        token.unref();
+        assert_eq!(token.has_ref(), Some(false));
+        token.ref_();
+        assert_eq!(token.has_ref(), Some(true));
+    } else {
+        assert_eq!(token.has_ref(), None);
+    }
+    token.refresh();
+    ticker.stop();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn unref_on_rearm() -> Result<(), JsValue> {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = UnrefTimeoutTickerFactory::new(move || n_.set(n_.get() + 1))?;
+    ticker.start()?;
+    sleep(Duration::from_millis(100)).await?;
+    assert!(n.get() > 0);
+    if ref_supported() {
+        assert_eq!(ticker.token().unwrap().has_ref(), Some(false));
+    }
+    ticker.stop();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn unref_immediate() -> Result<(), JsValue> {
+    if !ImmediateTicker::check() {
+        return Ok(());
+    }
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = UnrefImmediateTickerFactory::new(move || n_.set(n_.get() + 1))?;
+    ticker.start()?;
+    sleep(Duration::from_millis(20)).await?;
+    assert!(n.get() > 0);
+    if ref_supported() {
+        assert_eq!(ticker.token().unwrap().has_ref(), Some(false));
+    }
+    ticker.stop();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn unref_message_channel() -> Result<(), JsValue> {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = MessageChannelTickerFactory::new_unref(move || n_.set(n_.get() + 1))?;
+    ticker.start()?;
+    sleep(Duration::from_millis(20)).await?;
+    ticker.stop();
+    assert!(n.get() > 0);
+    Ok(())
+}
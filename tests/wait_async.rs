@@ -0,0 +1,75 @@
+use js_sys::{Atomics, Int32Array, SharedArrayBuffer};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::WaitAsyncTickerFactory;
+use wasm_ticker::ticker::WaitAsyncTicker;
+use wasm_ticker::time::sleep;
+use wasm_ticker::{NamedTicker, Ticker, TickerFactory};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn notify() -> Result<(), JsValue> {
+    if !WaitAsyncTicker::check() {
+        return Ok(());
+    }
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = WaitAsyncTickerFactory::new(move || n_.set(n_.get() + 1))?;
+    ticker.start()?;
+    sleep(Duration::from_millis(10)).await?;
+    assert_eq!(n.get(), 0);
+
+    for i in 1..=3 {
+        ticker.notify()?;
+        sleep(Duration::from_millis(10)).await?;
+        assert_eq!(n.get(), i);
+    }
+
+    ticker.stop();
+    ticker.notify()?;
+    sleep(Duration::from_millis(10)).await?;
+    assert_eq!(n.get(), 3);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn notify_from_task() -> Result<(), JsValue> {
+    if !WaitAsyncTicker::check() {
+        return Ok(());
+    }
+    let array = Int32Array::new(&SharedArrayBuffer::new(4));
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let array_ = array.clone();
+    let ticker = WaitAsyncTickerFactory::new_with_slot(array, 0, move || {
+        n_.set(n_.get() + 1);
+        // Arrives while the task runs, so must not be lost
+        if n_.get() == 1 {
+            Atomics::add(&array_, 0, 1).unwrap();
+            Atomics::notify(&array_, 0).unwrap();
+        }
+    })?;
+    ticker.start()?;
+    ticker.notify()?;
+    sleep(Duration::from_millis(20)).await?;
+    assert_eq!(n.get(), 2);
+    ticker.stop();
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn spawn() -> Result<(), JsValue> {
+    if !WaitAsyncTicker::check() {
+        return Ok(());
+    }
+    let b = Rc::new(Cell::new(false));
+    let b_ = b.clone();
+    WaitAsyncTicker::spawn(move || b_.set(true))?;
+    sleep(Duration::from_millis(10)).await?;
+    assert!(b.get());
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use wasm_ticker::factory::AutoTickerFactory;
+use wasm_ticker::{yield_if_needed, yield_now, Ticker, TickerFactory};
+
+wasm_bindgen_test_configure!(run_in_node_experimental);
+
+#[wasm_bindgen_test]
+async fn many() -> Result<(), JsValue> {
+    for _ in 0..10000 {
+        yield_now().await?;
+    }
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn lets_ticker_run() -> Result<(), JsValue> {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = AutoTickerFactory::new(move || n_.set(n_.get() + 1))?;
+    ticker.start()?;
+    for _ in 0..100 {
+        yield_now().await?;
+    }
+    ticker.stop();
+    assert!(n.get() > 0);
+    Ok(())
+}
+
+#[wasm_bindgen_test]
+async fn if_needed() -> Result<(), JsValue> {
+    let n = Rc::new(Cell::new(0u32));
+    let n_ = n.clone();
+    let ticker = AutoTickerFactory::new(move || n_.set(n_.get() + 1))?;
+    yield_now().await?;
+    ticker.start()?;
+
+    // Under budget, resolves without letting the ticker run
+    for _ in 0..100 {
+        yield_if_needed(Duration::from_secs(60)).await?;
+    }
+    assert_eq!(n.get(), 0);
+
+    // Budget used up, yields each time
+    for _ in 0..100 {
+        yield_if_needed(Duration::ZERO).await?;
+    }
+    ticker.stop();
+    assert!(n.get() > 0);
+    Ok(())
+}